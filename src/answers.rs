@@ -0,0 +1,76 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming the directory with the known answers.
+pub const ANSWERS_ENV: &str = "ADVENT_ANSWERS";
+
+/// Outcome of comparing a computed answer with the stored one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Mismatch { expected: String },
+    /// No answer is known yet for this part.
+    Missing,
+}
+
+/// Known answers, stored as one file per day and part (`day5a`, `day5b`, ...).
+pub struct Answers {
+    dir: PathBuf,
+}
+
+impl Answers {
+    pub fn new(dir: impl AsRef<Path>) -> Answers {
+        Answers { dir: dir.as_ref().to_path_buf() }
+    }
+
+    /// The directory from the environment, or `answers/`.
+    pub fn from_env() -> Answers {
+        Answers::new(std::env::var_os(ANSWERS_ENV).unwrap_or_else(|| "answers".into()))
+    }
+
+    fn path(&self, day: u8, part: usize) -> PathBuf {
+        self.dir.join(format!("day{}{}", day, (b'a' + part as u8) as char))
+    }
+
+    pub fn get(&self, day: u8, part: usize) -> io::Result<Option<String>> {
+        match std::fs::read_to_string(self.path(day, part)) {
+            Ok(answer) => Ok(Some(answer.trim_end().to_string())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn store(&self, day: u8, part: usize, answer: &str) -> io::Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(self.path(day, part), format!("{}\n", answer.trim_end()))
+    }
+
+    pub fn verify(&self, day: u8, part: usize, answer: &str) -> io::Result<Verdict> {
+        Ok(match self.get(day, part)? {
+            None => Verdict::Missing,
+            Some(expected) if expected == answer.trim_end() => Verdict::Correct,
+            Some(expected) => Verdict::Mismatch { expected },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let dir = std::env::temp_dir().join(format!("advent-answers-{}", std::process::id()));
+        let answers = Answers::new(&dir);
+
+        assert_eq!(answers.verify(1, 0, "24000").unwrap(), Verdict::Missing);
+        answers.store(1, 0, "24000").unwrap();
+        answers.store(10, 1, "##..\n..##\n").unwrap();
+        assert_eq!(std::fs::read_to_string(dir.join("day1a")).unwrap(), "24000\n");
+        assert_eq!(answers.verify(1, 0, "24000").unwrap(), Verdict::Correct);
+        assert_eq!(answers.verify(1, 0, "45000").unwrap(), Verdict::Mismatch { expected: "24000".to_string() });
+        assert_eq!(answers.verify(10, 1, "##..\n..##\n").unwrap(), Verdict::Correct);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,184 @@
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use regex::Regex;
+use crate::days::Day;
+use crate::Error;
+
+/// What is being timed: parsing the input or solving one of the parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Parse,
+    Part(usize),
+}
+
+impl Step {
+    fn from_name(name: &str) -> Option<Step> {
+        match name {
+            "parse" => Some(Step::Parse),
+            "a" => Some(Step::Part(0)),
+            "b" => Some(Step::Part(1)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Step::Parse => f.pad("parse"),
+            Step::Part(0) => f.pad("a"),
+            Step::Part(_) => f.pad("b"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub day: u8,
+    pub step: Step,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+/// A step whose median got slower than the baseline allows.
+#[derive(Debug, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub step: Step,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// Relative change of the median in percent.
+    pub change: f64,
+}
+
+fn measure(runs: usize, mut f: impl FnMut()) -> (Duration, Duration, Duration) {
+    let mut durations = Vec::new();
+    for _ in 0 .. runs.max(1) {
+        let start = Instant::now();
+        f();
+        durations.push(start.elapsed());
+    }
+    durations.sort();
+    (durations[0], durations[durations.len() / 2], durations[durations.len() - 1])
+}
+
+/// Times parsing and every part of a day `runs` times each.
+/// Fails with the first error of parsing or of a part instead of timing it.
+pub fn bench_day(day: &Day, input: &str, runs: usize) -> Result<Vec<Timing>, Error> {
+    let solution = day.solution;
+    let mut timings = Vec::new();
+
+    let parsed = solution.parse(input)?;
+    let (min, median, max) = measure(runs, || { let _ = black_box(solution.parse(black_box(input))); });
+    timings.push(Timing { day: day.day, step: Step::Parse, min, median, max });
+
+    for part in 0 .. solution.parts() {
+        solution.solve(parsed.as_ref(), part)?;
+        let (min, median, max) = measure(runs, || { let _ = black_box(solution.solve(parsed.as_ref(), part)); });
+        timings.push(Timing { day: day.day, step: Step::Part(part), min, median, max });
+    }
+    Ok(timings)
+}
+
+pub fn to_json(timings: &[Timing], runs: usize) -> String {
+    let results = timings.iter()
+                         .map(|t| format!("    {{\"day\": {}, \"step\": \"{}\", \"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                                          t.day, t.step, t.min.as_nanos(), t.median.as_nanos(), t.max.as_nanos()))
+                         .collect::<Vec<_>>();
+    format!("{{\n  \"runs\": {},\n  \"results\": [\n{}\n  ]\n}}\n", runs, results.join(",\n"))
+}
+
+/// Reads timings back from the output of [`to_json`]; fails unless every
+/// result is a timing and there is at least one.
+pub fn from_json(json: &str) -> Result<Vec<Timing>, String> {
+    if !Regex::new(r#""runs":\s*\d+"#).unwrap().is_match(json) {
+        return Err("no \"runs\" count".to_string());
+    }
+    let results = Regex::new(r#""results":\s*\[([^\]]*)\]"#).unwrap()
+                       .captures(json)
+                       .ok_or("no \"results\" list")?;
+    let re = Regex::new(r#"\{\s*"day":\s*(\d+),\s*"step":\s*"(\w+)",\s*"min_ns":\s*(\d+),\s*"median_ns":\s*(\d+),\s*"max_ns":\s*(\d+)\s*\}"#).unwrap();
+    let nanos = |text: &str| text.parse::<u64>().map(Duration::from_nanos).map_err(|e| e.to_string());
+
+    let timings = re.captures_iter(&results[1])
+                    .map(|cap| Ok(Timing {
+                        day: cap[1].parse().map_err(|_| format!("invalid day: {}", &cap[1]))?,
+                        step: Step::from_name(&cap[2]).ok_or_else(|| format!("invalid step: {}", &cap[2]))?,
+                        min: nanos(&cap[3])?,
+                        median: nanos(&cap[4])?,
+                        max: nanos(&cap[5])?,
+                    }))
+                    .collect::<Result<Vec<_>, String>>()?;
+    /* every entry of the list is an object */
+    let entries = results[1].matches('{').count();
+    if timings.is_empty() {
+        Err("no timings in \"results\"".to_string())
+    } else if timings.len() < entries {
+        Err(format!("only {} of {} results are timings", timings.len(), entries))
+    } else {
+        Ok(timings)
+    }
+}
+
+/// Compares the medians against the baseline; `threshold` is in percent.
+pub fn regressions(timings: &[Timing], baseline: &[Timing], threshold: f64) -> Vec<Regression> {
+    timings.iter()
+           .filter_map(|t| {
+               let base = baseline.iter().find(|b| b.day == t.day && b.step == t.step)?;
+               let change = (t.median.as_secs_f64() / base.median.as_secs_f64() - 1.0) * 100.0;
+               (change > threshold).then_some(Regression {
+                   day: t.day,
+                   step: t.step,
+                   baseline: base.median,
+                   current: t.median,
+                   change,
+               })
+           })
+           .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let timing = |day, step, median| Timing {
+            day,
+            step,
+            min: Duration::from_nanos(median / 2),
+            median: Duration::from_nanos(median),
+            max: Duration::from_nanos(median * 2),
+        };
+        let timings = vec![
+            timing(1, Step::Parse, 1000),
+            timing(1, Step::Part(0), 2000),
+            timing(1, Step::Part(1), 5000),
+        ];
+
+        let json = to_json(&timings, 7);
+        assert!(json.contains("\"runs\": 7"));
+        assert!(json.contains("{\"day\": 1, \"step\": \"b\", \"min_ns\": 2500, \"median_ns\": 5000, \"max_ns\": 10000}"));
+        assert_eq!(from_json(&json), Ok(timings.clone()));
+        assert_eq!(from_json("{}"), Err("no \"runs\" count".to_string()));
+        assert_eq!(from_json("{\"runs\": 7}"), Err("no \"results\" list".to_string()));
+        assert_eq!(from_json(&to_json(&[], 7)), Err("no timings in \"results\"".to_string()));
+        assert_eq!(from_json(&json.replace("\"step\": \"a\"", "\"part\": \"a\"")), Err("only 2 of 3 results are timings".to_string()));
+
+        let baseline = vec![
+            timing(1, Step::Parse, 1000),
+            timing(1, Step::Part(0), 1000),
+            timing(1, Step::Part(1), 4800),
+        ];
+        let slower = regressions(&timings, &baseline, 10.0);
+        assert_eq!(slower.len(), 1);
+        assert_eq!(slower[0].step, Step::Part(0));
+        assert_eq!(slower[0].change, 100.0);
+        assert!(regressions(&timings, &[], 10.0).is_empty());
+
+        let (min, median, max) = measure(3, || {});
+        assert!(min <= median && median <= max);
+    }
+}
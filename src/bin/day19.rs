@@ -0,0 +1,7 @@
+use advent::days::day19::Day19;
+
+static DAY: u8 = 19;
+
+fn main() {
+    advent::run(DAY, &Day19);
+}
@@ -1,120 +1,43 @@
+use std::path::PathBuf;
+use advent::days::day2::{analyze, builtin_strategies, parse_guide, score_guide, tournament, Day2, Game, Interpretation, Report};
+use advent::{option, InputSource};
+
 static DAY: u8 = 2;
 
 fn main() {
-    let input = advent::read_lines(DAY);
-    println!("{DAY}a: {}", game_score(&input, false));
-    println!("{DAY}b: {}", game_score(&input, true));
-}
-
-#[derive(PartialEq, Eq, Clone, Copy)]
-enum Shape {
-    Rock,
-    Paper,
-    Scissors,
-}
-
-impl Shape {
-    fn score(&self) -> u32 {
-        match self {
-            Shape::Rock => 1,
-            Shape::Paper => 2,
-            Shape::Scissors => 3,
-        }
-    }
-
-    fn wins_against(&self) -> Shape {
-        match self {
-            Shape::Rock => Shape::Scissors,
-            Shape::Paper => Shape::Rock,
-            Shape::Scissors => Shape::Paper,
-        }
-    }
-
-    fn draws_against(&self) -> Shape {
-        *self
-    }
-
-    fn loses_against(&self) -> Shape {
-        match self {
-            Shape::Rock => Shape::Paper,
-            Shape::Paper => Shape::Scissors,
-            Shape::Scissors => Shape::Rock,
-        }
-    }
-}
-
-struct Round {
-    opponent: Shape,
-    you: Shape,
-}
-
-impl Round {
-    fn new(input: &str, result_indicator: bool) -> Round {
-        assert_eq!(input.len(), 3);
-        let chars : Vec<char> = input.chars().collect();
-        let opponent = match chars[0] {
-            'A' => Shape::Rock,
-            'B' => Shape::Paper,
-            'C' => Shape::Scissors,
-            _ => unreachable!(),
-        };
-        let you = if !result_indicator {
-            match chars[2] {
-                'X' => Shape::Rock,
-                'Y' => Shape::Paper,
-                'Z' => Shape::Scissors,
-                _ => unreachable!(),
-            }
-        } else {
-            match chars[2] {
-                'X' => opponent.wins_against(),
-                'Y' => opponent.draws_against(),
-                'Z' => opponent.loses_against(),
-                _ => unreachable!(),
-            }
-        };
-
-        Round { opponent, you }
-    }
-
-    fn outcome_score(&self) -> u32 {
-        if self.you.wins_against() == self.opponent {
-            6
-        } else if self.you.draws_against() == self.opponent {
-            3
-        } else if self.you.loses_against() == self.opponent {
-            0
-        } else {
-            unreachable!()
+    let args = std::env::args().collect::<Vec<_>>();
+    let game_file = option(&args, "--game");
+    let analyze_guide = args.iter().any(|arg| arg == "--analyze");
+    let play_tournament = args.iter().any(|arg| arg == "--tournament");
+    if game_file.is_none() && !analyze_guide && !play_tournament {
+        advent::run(DAY, &Day2);
+        return;
+    }
+
+    /* a variant of the game from --game FILE, or rock paper scissors */
+    let game = match &game_file {
+        Some(path) => {
+            let definition = InputSource::File(PathBuf::from(path)).read().unwrap_or_else(|err| advent::exit_with(err));
+            Game::parse(&definition).unwrap_or_else(|err| advent::exit_with(format!("{}: {}", path, err)))
+        },
+        None => Game::rock_paper_scissors(),
+    };
+    let guide = parse_guide(&advent::read_lines(DAY), &game)
+        .unwrap_or_else(|err| advent::exit_with(format!("{}: {}", InputSource::for_day(DAY), err)));
+
+    if analyze_guide {
+        /* score the guide under every interpretation of the second column */
+        print!("{}", Report { game: &game, analyses: analyze(&game, &guide) });
+    } else if play_tournament {
+        let seed = option(&args, "--seed").map(|seed| seed.parse().unwrap_or_else(|_| advent::exit_with("--seed needs a number")))
+                                          .unwrap_or(2022);
+        let strategies = builtin_strategies(&game, &guide);
+        print!("{}", tournament(&game, &strategies, 1000, seed));
+    } else {
+        /* both parts, with the game's shapes in the order of the definition */
+        println!("{}a: {}", DAY, score_guide(&game, &guide, &Interpretation::Shapes(game.shapes().collect())).score);
+        if guide.iter().all(|&(_, column)| column < 3) {
+            println!("{}b: {}", DAY, score_guide(&game, &guide, &Interpretation::Result).score);
         }
     }
-
-    fn score(&self) -> u32 {
-        self.outcome_score() + self.you.score()
-    }
-}
-
-fn game_score(input: &[String], result_indicator: bool) -> u32 {
-    let rounds = input.iter()
-                      .map(|x| Round::new(x, result_indicator))
-                      .collect::<Vec<_>>();
-    rounds.iter()
-          .map(|x| x.score())
-          .sum()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test() {
-        let input = [
-            "A Y".to_string(),
-            "B X".to_string(),
-            "C Z".to_string(),
-        ];
-        assert_eq!(game_score(&input, false), 15);
-        assert_eq!(game_score(&input, true), 12);
-    }
 }
@@ -1,70 +1,40 @@
-use std::collections::HashSet;
+use std::path::PathBuf;
+use advent::days::day3::{badges, shared_items, sum_badge_priorities, sum_priorities, Day3, Priorities};
+use advent::{option, InputSource};
 
 static DAY: u8 = 3;
 
 fn main() {
-    let input = advent::read_lines(DAY);
-    println!("{DAY}a: {}", sum_priorities(&input));
-    println!("{DAY}b: {}", sum_badge_priorities(&input));
-}
-
-fn item_value(item: char) -> u32 {
-    if item.is_ascii_lowercase() {
-        item.to_digit(36).unwrap() - 9
-    } else if item.is_ascii_uppercase() {
-        item.to_digit(36).unwrap() + 17
-    } else {
-        panic!("unexpected common character: {}", item);
+    let args = std::env::args().collect::<Vec<_>>();
+    let report = args.iter().any(|arg| arg == "--report");
+    if option(&args, "--priorities").is_none() && option(&args, "--group-size").is_none() && !report {
+        advent::run(DAY, &Day3);
+        return;
     }
-}
-
-fn sum_priorities(input: &[String]) -> u32 {
-    let mut priorities = 0;
-
-    for line in input {
-        let (first, second) = line.split_at(line.len() / 2);
-        let first = first.chars().collect::<HashSet<_>>();
-        let second = second.chars().collect::<HashSet<_>>();
-        let common = first.intersection(&second).copied().collect::<Vec<_>>();
-        assert_eq!(common.len(), 1);
-        priorities += item_value(*common.first().unwrap());
-    }
-
-    priorities
-}
-
-fn sum_badge_priorities(input: &[String]) -> u32 {
-    let mut priorities = 0;
 
-    for group in input.chunks(3) {
-        let first = group[0].chars().collect::<HashSet<_>>();
-        let second = group[1].chars().collect::<HashSet<_>>();
-        let third = group[2].chars().collect::<HashSet<_>>();
-        let common = first.intersection(&second).copied().collect::<HashSet<_>>();
-        let common = common.intersection(&third).copied().collect::<Vec<_>>();
-        assert_eq!(common.len(), 1);
-        priorities += item_value(*common.first().unwrap());
+    /* a custom item alphabet instead of the letters */
+    let priorities = match option(&args, "--priorities") {
+        Some(path) => {
+            let definition = InputSource::File(PathBuf::from(&path)).read().unwrap_or_else(|err| advent::exit_with(err));
+            Priorities::parse(&definition).unwrap_or_else(|err| advent::exit_with(format!("{}: {}", path, err)))
+        },
+        None => Priorities::letters(),
+    };
+    let group_size = option(&args, "--group-size").map(|size| size.parse().unwrap_or_else(|_| advent::exit_with("--group-size needs a number")))
+                                            .unwrap_or(3);
+    let input = advent::parse_input(DAY, &Day3);
+    let fail = |err| -> ! { advent::exit_with(format!("{}: {}", InputSource::for_day(DAY), err)) };
+
+    if report {
+        /* numbered from 0 like in the errors */
+        for (idx, item) in shared_items(&priorities, &input).unwrap_or_else(|err| fail(err)).into_iter().enumerate() {
+            println!("rucksack {}: {} ({})", idx, item, priorities.weight(item).unwrap_or(0));
+        }
+        for (idx, item) in badges(&priorities, &input, group_size).unwrap_or_else(|err| fail(err)).into_iter().enumerate() {
+            println!("group {}: {} ({})", idx, item, priorities.weight(item).unwrap_or(0));
+        }
     }
-
-    priorities
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test() {
-        let input = [
-            "vJrwpWtwJgWrhcsFMMfFFhFp".to_string(),
-            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL".to_string(),
-            "PmmdzqPrVvPwwTWBwg".to_string(),
-            "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn".to_string(),
-            "ttgJtRGJQctTZtZT".to_string(),
-            "CrZsJsPPZsGzwwsLwLmpwMDw".to_string(),
-        ];
-
-        assert_eq!(sum_priorities(&input), 157);
-        assert_eq!(sum_badge_priorities(&input), 70);
+    for (name, sum) in [('a', sum_priorities(&priorities, &input)), ('b', sum_badge_priorities(&priorities, &input, group_size))] {
+        println!("{}{}: {}", DAY, name, sum.unwrap_or_else(|err| fail(err)));
     }
 }
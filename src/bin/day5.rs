@@ -1,113 +1,62 @@
-use std::collections::VecDeque;
-use regex::Regex;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use advent::days::day5::{frame_size, plan, render_frame, CrateMover, Day5, Model, StepSize};
+use advent::image::Y4mWriter;
+use advent::{option, InputSource};
 
 static DAY: u8 = 5;
 
 fn main() {
-    let input = advent::read_lines(DAY);
-    println!("{DAY}a: {}", find_top_crates(&input));
-    println!("{DAY}b: {}", find_top_crates_9001(&input));
-}
-
-struct CrateMover {
-    instructions: Vec<Instruction>,
-    stacks: Vec<VecDeque<char>>,
-}
-
-struct Instruction {
-    amount: usize,
-    from: usize,
-    to: usize,
-}
-
-impl CrateMover {
-    fn new(input: &[String]) -> CrateMover {
-        let re_instruction = Regex::new("move ([0-9]+) from ([0-9]+) to ([0-9]+)").unwrap();
-
-        let mut instructions = Vec::new();
-        let mut stacks = Vec::new();
-        for line in input {
-            if let Some(cap) = re_instruction.captures(line) {
-                instructions.push(Instruction {
-                    amount: cap[1].parse::<usize>().unwrap(),
-                    from: cap[2].parse::<usize>().unwrap() - 1,
-                    to: cap[3].parse::<usize>().unwrap() - 1,
-                });
-            }
-
-            if line.find('[').is_some() {
-                let chars = line.chars().collect::<Vec<_>>();
-                for (index, chunk) in chars.chunks(4).enumerate() {
-                    // chunk: "[x] "
-                    let name = chunk[1];
-                    if name == ' ' {
-                        // empty position
-                        continue;
-                    }
-                    if index >= stacks.len() {
-                        stacks.resize(index + 1, VecDeque::new());
-                    }
-                    stacks[index].push_front(name);
-                }
-            }
+    let args = std::env::args().collect::<Vec<_>>();
+    let model = if args.iter().any(|arg| arg == "--9001") { Model::CrateMover9001 } else { Model::CrateMover9000 };
+    if let Some(path) = option(&args, "--plan") {
+        /* instructions from the input's drawing to the drawing in the given file */
+        let target = InputSource::File(PathBuf::from(&path)).read().unwrap_or_else(|err| advent::exit_with(err));
+        let target = CrateMover::new(&advent::lines(&target));
+        let cratemover = advent::parse_input(DAY, &Day5);
+        match plan(cratemover.stacks(), target.stacks(), model) {
+            Ok(instructions) => instructions.iter().for_each(|instruction| println!("{}", instruction)),
+            Err(err) => advent::exit_with(format!("{}: {}", path, err)),
         }
-
-        CrateMover { stacks, instructions }
+        return;
     }
 
-    fn move_crates(&mut self) {
-        for instruction in &self.instructions {
-            for _ in 0 .. instruction.amount {
-                let name = self.stacks[instruction.from].pop_back().expect("Stack has no crate");
-                self.stacks[instruction.to].push_back(name);
-            }
-        }
+    let video = option(&args, "--video");
+    let frames = option(&args, "--frames");
+    if video.is_none() && frames.is_none() {
+        advent::run(DAY, &Day5);
+        return;
     }
 
-    fn move_crates_9001(&mut self) {
-        for instruction in &self.instructions {
-            let drain_from = self.stacks[instruction.from].len() - instruction.amount;
-            let moved_stack = self.stacks[instruction.from].drain(drain_from ..).collect::<Vec<_>>();
-            self.stacks[instruction.to].extend(moved_stack);
+    /* regenerate the visualizations: --video FILE.y4m and/or --frames DIR, with --9001 and --per-instruction */
+    let step_size = if args.iter().any(|arg| arg == "--per-instruction") { StepSize::Instruction } else { StepSize::Crate };
+    let cratemover = advent::parse_input(DAY, &Day5);
+    if let Err(err) = cratemover.validate() {
+        advent::exit_with(err);
+    }
+    let capacity = cratemover.stacks().iter().map(|stack| stack.len()).sum();
+    let (width, height) = frame_size(cratemover.stacks().len(), capacity);
+
+    /* write failures name the file and end the run like any other error */
+    let fail = |path: &str, err: std::io::Error| -> ! { advent::exit_with(format!("{}: {}", path, err)) };
+    let mut video = video.map(|path| {
+        let file = File::create(&path).unwrap_or_else(|err| fail(&path, err));
+        let writer = Y4mWriter::new(BufWriter::new(file), width, height, 30).unwrap_or_else(|err| fail(&path, err));
+        (path, writer)
+    });
+    for (idx, stacks) in cratemover.steps(model, step_size).enumerate() {
+        let frame = render_frame(&stacks, capacity);
+        if let Some((path, video)) = &mut video {
+            video.write_frame(&frame).unwrap_or_else(|err| fail(path, err));
+        }
+        if let Some(dir) = &frames {
+            let path = format!("{}/frame{:05}.ppm", dir, idx);
+            let mut file = BufWriter::new(File::create(&path).unwrap_or_else(|err| fail(&path, err)));
+            frame.write_ppm(&mut file).and_then(|()| file.flush()).unwrap_or_else(|err| fail(&path, err));
         }
     }
-}
-
-fn find_top_crates(input: &[String]) -> String {
-    let mut cratemover = CrateMover::new(input);
-    cratemover.move_crates();
-    cratemover.stacks.iter()
-                     .map(|stack| stack.back().unwrap())
-                     .collect()
-}
-
-fn find_top_crates_9001(input: &[String]) -> String {
-    let mut cratemover = CrateMover::new(input);
-    cratemover.move_crates_9001();
-    cratemover.stacks.iter()
-                     .map(|stack| stack.back().unwrap())
-                     .collect()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test() {
-        let input = [
-            "    [D]    ".to_string(),
-            "[N] [C]    ".to_string(),
-            "[Z] [M] [P]".to_string(),
-            " 1   2   3 ".to_string(),
-            "".to_string(),
-            "move 1 from 2 to 1".to_string(),
-            "move 3 from 1 to 3".to_string(),
-            "move 2 from 2 to 1".to_string(),
-            "move 1 from 1 to 2".to_string(),
-        ];
-
-        assert_eq!(find_top_crates(&input), "CMZ");
-        assert_eq!(find_top_crates_9001(&input), "MCD");
+    if let Some((path, video)) = video {
+        video.into_inner().flush().unwrap_or_else(|err| fail(&path, err));
     }
 }
@@ -1,49 +1,49 @@
-use std::collections::HashSet;
+use std::fs::File;
+use std::io::{self, Read};
+use advent::days::day6::{first_markers, markers, Day6};
+use advent::{option, InputSource};
 
 static DAY: u8 = 6;
 
 fn main() {
-    let input = advent::read_file(DAY);
-    println!("{DAY}a: {}", start_of_packet(&input));
-    println!("{DAY}b: {}", start_of_message(&input));
-}
-
-fn start_of_prefix(input: &str, prefix_len: usize) -> usize {
-    input.trim()
-         .chars()
-         .collect::<Vec<_>>()
-         .windows(prefix_len)
-         .enumerate()
-         .map(|(pos, window)| (pos + prefix_len, window.iter().collect::<HashSet<_>>()))
-         .find(|(_, set)| set.len() == prefix_len)
-         .unwrap_or_else(|| panic!("set should contain {} different characters", prefix_len))
-         .0
-}
-
-fn start_of_packet(input: &str) -> usize {
-    start_of_prefix(input, 4)
-}
-
-fn start_of_message(input: &str) -> usize {
-    start_of_prefix(input, 14)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test() {
-        assert_eq!(start_of_packet("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 7);
-        assert_eq!(start_of_packet("bvwbjplbgvbhsrlpgdmjqwftvncz"), 5);
-        assert_eq!(start_of_packet("nppdvjthqldpwncqszvftbrmjlhg"), 6);
-        assert_eq!(start_of_packet("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 10);
-        assert_eq!(start_of_packet("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 11);
+    let args = std::env::args().collect::<Vec<_>>();
+    if !args.iter().any(|arg| arg == "--stream") {
+        advent::run(DAY, &Day6);
+        return;
+    }
 
-        assert_eq!(start_of_message("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 19);
-        assert_eq!(start_of_message("bvwbjplbgvbhsrlpgdmjqwftvncz"), 23);
-        assert_eq!(start_of_message("nppdvjthqldpwncqszvftbrmjlhg"), 23);
-        assert_eq!(start_of_message("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 29);
-        assert_eq!(start_of_message("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 26);
+    /* window lengths from --lengths 4,14 */
+    let custom = option(&args, "--lengths");
+    let lengths = match &custom {
+        Some(list) => list.split(',')
+                          .map(|length| length.parse::<usize>().ok().filter(|&length| length > 0))
+                          .collect::<Option<Vec<_>>>()
+                          .unwrap_or_else(|| advent::exit_with(format!("--lengths needs a list like 4,14, not {:?}", list))),
+        None if args.iter().any(|arg| arg == "--lengths") => advent::exit_with("--lengths needs a list like 4,14"),
+        None => vec![4, 14],
+    };
+
+    /* read the signal piece by piece instead of loading it, for captures of any size */
+    let input = InputSource::for_day(DAY);
+    let fail = |err: io::Error| -> ! { advent::exit_with(format!("{}: {}", input, err)) };
+    let reader: Box<dyn Read> = match &input {
+        InputSource::File(path) => Box::new(File::open(path).unwrap_or_else(|err| fail(err))),
+        InputSource::Stdin => Box::new(io::stdin().lock()),
+    };
+    if args.iter().any(|arg| arg == "--all") {
+        for marker in markers(reader, &lengths).unwrap_or_else(|err| fail(err)) {
+            let marker = marker.unwrap_or_else(|err| fail(err));
+            println!("{} {}", marker.length, marker.position);
+        }
+    } else {
+        /* the parts without --lengths, the lengths themselves with it */
+        let found = first_markers(reader, &lengths).unwrap_or_else(|err| fail(err));
+        for (idx, (length, position)) in lengths.iter().zip(found).enumerate() {
+            let name = if custom.is_some() { length.to_string() } else { format!("{}{}", DAY, (b'a' + idx as u8) as char) };
+            match position {
+                Some(position) => println!("{}: {}", name, position),
+                None => println!("{}: no marker", name),
+            }
+        }
     }
 }
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A repeating stretch found in a simulation, together with the values
+/// accumulated up to the end of its first repetition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    /// Number of steps before the repetition starts.
+    pub start: usize,
+    pub length: usize,
+    /* sums[n] is the accumulated value after n steps, up to start + length */
+    sums: Vec<u64>,
+}
+
+/// Consumes `(state, delta)` steps until a state repeats.
+/// The state has to describe everything that influences the following steps;
+/// `delta` is the amount the step adds to the accumulated value.
+/// Returns `None` if the steps end before any state repeats.
+pub fn detect<S: Hash + Eq>(steps: impl IntoIterator<Item = (S, u64)>) -> Option<Cycle> {
+    let mut seen = HashMap::new();
+    let mut sums = vec![0];
+    for (step, (state, delta)) in steps.into_iter().enumerate() {
+        if let Some(&start) = seen.get(&state) {
+            return Some(Cycle { start, length: step - start, sums });
+        }
+        seen.insert(state, step);
+        sums.push(sums[step] + delta);
+    }
+    None
+}
+
+impl Cycle {
+    /// Accumulated value after `steps` steps.
+    pub fn value_after(&self, steps: u64) -> u128 {
+        let (start, length) = (self.start as u64, self.length as u64);
+        if steps <= start + length {
+            return self.sums[steps as usize] as u128;
+        }
+
+        let per_cycle = (self.sums[self.start + self.length] - self.sums[self.start]) as u128;
+        let cycles = (steps - start) / length;
+        let remaining = ((steps - start) % length) as usize;
+        self.sums[self.start] as u128
+            + cycles as u128 * per_cycle
+            + (self.sums[self.start + remaining] - self.sums[self.start]) as u128
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        /* states 0, 1, 2, 3, 4, 2, 3, 4, ... adding the state as value */
+        let states = (0 ..).map(|n: u64| if n < 2 { n } else { 2 + (n - 2) % 3 });
+        let cycle = detect(states.clone().map(|s| (s, s))).unwrap();
+        assert_eq!((cycle.start, cycle.length), (2, 3));
+
+        let sum = |n| states.clone().take(n).sum::<u64>() as u128;
+        for n in 0 .. 20 {
+            assert_eq!(cycle.value_after(n as u64), sum(n));
+        }
+        let cycles = (u64::MAX - 2) / 3;
+        let remaining = [0, 2, 5][((u64::MAX - 2) % 3) as usize];
+        assert_eq!(cycle.value_after(u64::MAX), 1 + cycles as u128 * 9 + remaining);
+
+        assert_eq!(detect([(1, 1), (2, 1)]), None);
+    }
+}
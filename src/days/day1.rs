@@ -0,0 +1,73 @@
+use crate::{lines, Answer, Error, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+
+    fn parse(&self, input: &str) -> Result<Vec<u32>, Error> {
+        elf_calories(&lines(input))
+    }
+
+    fn part1(&self, calories: &Vec<u32>) -> Result<Answer, Error> {
+        Ok(most_calories_elf(calories, 1).into())
+    }
+
+    fn part2(&self, calories: &Vec<u32>) -> Result<Answer, Error> {
+        Ok(most_calories_elf(calories, 3).into())
+    }
+}
+
+/* calories carried by every elf, the elves being separated by blank lines */
+fn elf_calories(input: &[String]) -> Result<Vec<u32>, Error> {
+    let mut calories = Vec::new();
+    let mut elf = None;
+    for (idx, line) in input.iter().enumerate() {
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() {
+            calories.extend(elf.take());
+            continue;
+        }
+        let snack = line.parse::<u32>().map_err(|err| Error::parse(idx + 1, line, err))?;
+        *elf.get_or_insert(0) += snack;
+    }
+    calories.extend(elf);
+    Ok(calories)
+}
+
+fn most_calories_elf(calories: &[u32], elf_count: usize) -> u32 {
+    let mut calories = calories.to_vec();
+    calories.sort();
+    calories.iter().rev().take(elf_count).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "1000".to_string(),
+            "2000".to_string(),
+            "3000".to_string(),
+            "".to_string(),
+            "4000".to_string(),
+            "".to_string(),
+            "5000".to_string(),
+            "6000".to_string(),
+            "".to_string(),
+            "7000".to_string(),
+            "8000".to_string(),
+            "9000".to_string(),
+            "".to_string(),
+            "10000".to_string(),
+        ];
+        let calories = elf_calories(&input).unwrap();
+        assert_eq!(calories, [6000, 4000, 11000, 24000, 10000]);
+        assert_eq!(most_calories_elf(&calories, 1), 24000);
+        assert_eq!(most_calories_elf(&calories, 3), 45000);
+        assert_eq!(elf_calories(&lines("\n\n1\r\n2\n\n\n3\n")).unwrap(), [3, 3]);
+        assert_eq!(elf_calories(&lines("100\n\n2x0\n")).unwrap_err().to_string(), "line 3: invalid digit found in string (\"2x0\")");
+    }
+}
@@ -0,0 +1,98 @@
+use crate::geom::Point2;
+use crate::grid::Grid;
+use crate::search;
+use crate::{lines, Answer, Error, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = (Grid<u32>, Point2, Point2);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_map(&lines(input)))
+    }
+
+    fn part1(&self, (map, start, end): &Self::Input) -> Result<Answer, Error> {
+        Ok(steps_required_start(map, start, end).ok_or_else(|| Error::invalid("the best signal can't be reached from the start"))?.into())
+    }
+
+    fn part2(&self, (map, _, end): &Self::Input) -> Result<Answer, Error> {
+        Ok(steps_required_any_a(map, end).ok_or_else(|| Error::invalid("the best signal can't be reached from elevation a"))?.into())
+    }
+}
+
+fn parse_map(input: &[String]) -> (Grid<u32>, Point2, Point2) {
+    let symbols = Grid::parse(input, |c| c);
+    let find = |symbol| symbols.iter()
+                               .find(|&(_, &c)| c == symbol)
+                               .map(|(pos, _)| Point2::from(pos))
+                               .expect("map should contain start and end");
+    let (start, end) = (find('S'), find('E'));
+
+    let map = Grid::parse(input, |c| {
+        let elevation = match c {
+            'S' => 'a',
+            'E' => 'z',
+            e => e,
+        };
+        elevation.to_digit(36).unwrap()
+    });
+
+    (map, start, end)
+}
+
+fn steps_required(map: &Grid<u32>, end: &Point2, found_position: impl Fn(&Point2, u32) -> bool) -> Option<usize> {
+    /* walking backwards from the end, so the allowed climb is reversed */
+    let neighbors = |pos: &Point2| {
+        let elevation = map[*pos];
+        pos.neighbors4()
+           .into_iter()
+           .filter(move |&next| map.get(next.into()).is_some_and(|&e| elevation <= e + 1))
+    };
+    let path = search::bfs([*end], neighbors, |pos| found_position(pos, map[*pos]))?;
+    Some(path.cost)
+}
+
+fn steps_required_start(map: &Grid<u32>, start: &Point2, end: &Point2) -> Option<usize> {
+    let found_position = |pos: &Point2, _ele: u32| -> bool {
+        /* looking for the starting position */
+        pos == start
+    };
+    steps_required(map, end, found_position)
+}
+
+fn steps_required_any_a(map: &Grid<u32>, end: &Point2) -> Option<usize> {
+    let found_position = |_pos: &Point2, ele: u32| -> bool {
+        /* looking for any location with elevation 'a' */
+        ele == 'a'.to_digit(36).unwrap()
+    };
+    steps_required(map, end, found_position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "Sabqponm",
+            "abcryxxl",
+            "accszExk",
+            "acctuvwj",
+            "abdefghi",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+
+        let (map, start, end) = parse_map(&input);
+        assert_eq!(steps_required_start(&map, &start, &end), Some(31));
+        assert_eq!(steps_required_any_a(&map, &end), Some(29));
+
+        let input = [
+            "Sbz",
+            "zzE",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+
+        let (map, start, end) = parse_map(&input);
+        assert_eq!(steps_required_start(&map, &start, &end), None);
+    }
+}
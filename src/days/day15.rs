@@ -0,0 +1,126 @@
+use regex::Regex;
+use crate::geom::Point2;
+use crate::interval::{Interval, RangeSet};
+use crate::{lines, Answer, Error, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<SensorReading>;
+
+    fn parse(&self, input: &str) -> Result<Vec<SensorReading>, Error> {
+        Ok(parse_readings(&lines(input)))
+    }
+
+    fn part1(&self, readings: &Vec<SensorReading>) -> Result<Answer, Error> {
+        Ok(positions_without_beacon(readings, 2000000).into())
+    }
+
+    fn part2(&self, readings: &Vec<SensorReading>) -> Result<Answer, Error> {
+        Ok(tuning_frequency(readings, 0, 4000000).into())
+    }
+}
+
+pub struct SensorReading {
+    sensor: Point2,
+    closest: Point2,
+    range: isize,
+}
+
+impl SensorReading {
+    fn new(input: &str) -> SensorReading {
+        let re = Regex::new("Sensor at x=(-?[0-9]+), y=(-?[0-9]+): closest beacon is at x=(-?[0-9]+), y=(-?[0-9]+)").unwrap();
+
+        let cap = re.captures(input).expect("input should match regex");
+        let sensor = Point2 {
+            x: cap[1].parse::<isize>().unwrap(),
+            y: cap[2].parse::<isize>().unwrap(),
+        };
+        let closest = Point2 {
+            x: cap[3].parse::<isize>().unwrap(),
+            y: cap[4].parse::<isize>().unwrap(),
+        };
+        let range = sensor.manhattan(&closest) as isize;
+        SensorReading { sensor, closest, range }
+    }
+
+    fn signal_in_range(&self, pos: &Point2) -> bool {
+        self.sensor.manhattan(pos) as isize <= self.range
+    }
+}
+
+fn parse_readings(input: &[String]) -> Vec<SensorReading> {
+    input.iter()
+         .map(|x| SensorReading::new(x))
+         .collect()
+}
+
+fn positions_without_beacon(readings: &[SensorReading], y: isize) -> u64 {
+    /* every sensor covers an interval of the row, shrinking with the distance to it */
+    let covered = readings.iter()
+                          .filter_map(|r| {
+                              let remaining = r.range - r.sensor.y.abs_diff(y) as isize;
+                              (remaining >= 0).then(|| Interval::closed((r.sensor.x - remaining) as i64, (r.sensor.x + remaining) as i64))
+                          })
+                          .collect::<RangeSet>();
+
+    let mut beacons = readings.iter()
+                              .filter(|r| r.closest.y == y && covered.contains(r.closest.x as i64))
+                              .map(|r| r.closest.x)
+                              .collect::<Vec<_>>();
+    beacons.sort();
+    beacons.dedup();
+    covered.len() - beacons.len() as u64
+}
+
+fn tuning_frequency(readings: &[SensorReading], min_coord: isize, max_coord: isize) -> isize {
+    let mut x = min_coord;
+    let mut y = min_coord;
+    loop {
+        if x > max_coord {
+            x = min_coord;
+            y += 1;
+        }
+        let pos = Point2 { x, y };
+        let reading = readings.iter()
+                              .find(|r| r.signal_in_range(&pos));
+
+        let reading = match reading {
+            Some(r) => r,
+            None => return x * 4000000 + y,
+        };
+
+        let dist_y = reading.sensor.y.abs_diff(pos.y) as isize;
+        /* move position outside of range of current sensor */
+        x = reading.sensor.x + reading.range + 1 - dist_y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "Sensor at x=2, y=18: closest beacon is at x=-2, y=15",
+            "Sensor at x=9, y=16: closest beacon is at x=10, y=16",
+            "Sensor at x=13, y=2: closest beacon is at x=15, y=3",
+            "Sensor at x=12, y=14: closest beacon is at x=10, y=16",
+            "Sensor at x=10, y=20: closest beacon is at x=10, y=16",
+            "Sensor at x=14, y=17: closest beacon is at x=10, y=16",
+            "Sensor at x=8, y=7: closest beacon is at x=2, y=10",
+            "Sensor at x=2, y=0: closest beacon is at x=2, y=10",
+            "Sensor at x=0, y=11: closest beacon is at x=2, y=10",
+            "Sensor at x=20, y=14: closest beacon is at x=25, y=17",
+            "Sensor at x=17, y=20: closest beacon is at x=21, y=22",
+            "Sensor at x=16, y=7: closest beacon is at x=15, y=3",
+            "Sensor at x=14, y=3: closest beacon is at x=15, y=3",
+            "Sensor at x=20, y=1: closest beacon is at x=15, y=3",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+
+        let readings = parse_readings(&input);
+        assert_eq!(positions_without_beacon(&readings, 10), 26);
+        assert_eq!(tuning_frequency(&readings, 0, 20), 56000011);
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::{HashMap, HashSet};
+use regex::Regex;
+use itertools::Itertools;
+use crate::search;
+use crate::{lines, Answer, Error, Solution};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Volcano;
+
+    fn parse(&self, input: &str) -> Result<Volcano, Error> {
+        Ok(Volcano::new(&lines(input)))
+    }
+
+    fn part1(&self, volcano: &Volcano) -> Result<Answer, Error> {
+        Ok(most_pressure(volcano, false).into())
+    }
+
+    fn part2(&self, volcano: &Volcano) -> Result<Answer, Error> {
+        Ok(most_pressure(volcano, true).into())
+    }
+}
+
+#[derive(PartialEq,Eq)]
+struct Valve {
+    name: String,
+    flowrate: usize,
+    tunnels: Vec<String>,
+}
+
+impl Valve {
+    fn new(input: &str) -> Valve {
+        let re = Regex::new("Valve ([A-Z]+) has flow rate=([0-9]+); tunnels? leads? to valves? ([A-Z, ]+)").unwrap();
+
+        let caps = re.captures(input).expect("input should match regex");
+        let name = String::from(&caps[1]);
+        let flowrate = caps[2].parse().unwrap();
+        let tunnels = caps[3].split(", ").map(String::from).collect();
+
+        Valve { name, flowrate, tunnels }
+    }
+}
+
+fn pressure_from(map: &HashMap<&String,&Valve>, distances: &HashMap<(String,String),usize>, from: &str, time: usize, opened: &HashSet<String>) -> usize {
+    let valves_with_flowrate = map.values()
+                                  .filter(|v| v.flowrate > 0)
+                                  .map(|v| v.name.clone())
+                                  .collect::<Vec<_>>();
+
+    let mut max_pressure = 0;
+    for to in valves_with_flowrate {
+        if to == from || opened.contains(&to) {
+            /* no need to travel here if valve already open */
+            continue;
+        }
+        let distance = distances[&(from.to_string(),to.clone())];
+        if distance >= time {
+            /* no time left to open another valve at destination */
+            continue;
+        }
+
+        let new_from = to.clone();
+
+        let mut time = time;
+        time -= 1;  // 1 minute to open valve
+        time -= distance;  // time to travel to next valve
+        let mut opened = opened.clone();
+        opened.insert(new_from.clone());
+        let mut pressure = pressure_from(map, distances, &new_from, time, &opened);
+        pressure += time * map[&new_from].flowrate;
+        max_pressure = max_pressure.max(pressure);
+    }
+
+    max_pressure
+}
+
+pub struct Volcano {
+    map: HashMap<String,Valve>,
+    distances: HashMap<(String,String),usize>,
+}
+
+impl Volcano {
+    fn new(input: &[String]) -> Volcano {
+        let map = input.iter()
+                       .map(|x| Valve::new(x))
+                       .map(|x| (x.name.clone(), x))
+                       .collect::<HashMap<String,Valve>>();
+
+        let mut distances = HashMap::new();
+
+        let valves_with_flowrate = map.values()
+                                      .filter(|v| v.flowrate > 0)
+                                      .collect::<Vec<_>>();
+
+        for valve_from in valves_with_flowrate.iter().chain([&map[START]].iter()) {
+            let from_distances = search::bfs_distances([valve_from.name.as_str()], |&pos| map[pos].tunnels.iter().map(String::as_str));
+            for valve_to in &valves_with_flowrate {
+                if valve_from.name == valve_to.name {
+                    continue;
+                }
+                let distance = from_distances[valve_to.name.as_str()];
+                distances.insert((valve_from.name.clone(), valve_to.name.clone()), distance);
+            }
+        }
+
+        Volcano { map, distances }
+    }
+}
+
+static START: &str = "AA";
+
+fn most_pressure(volcano: &Volcano, with_elephant: bool) -> usize {
+    let Volcano { map, distances } = volcano;
+    let start = START;
+    let valves_with_flowrate = map.values()
+                                  .filter(|v| v.flowrate > 0)
+                                  .collect::<Vec<_>>();
+    let opened = HashSet::new();
+    let time = match with_elephant {
+        false => 30,
+        true => 26,
+    };
+    if !with_elephant {
+        let map = map.iter().collect();
+        return pressure_from(&map, distances, start, time, &opened)
+    }
+
+    let mut max_pressure = 0;
+    for path in valves_with_flowrate.iter().cloned().combinations(valves_with_flowrate.len() / 2) {
+        let map_you = map.iter()
+                         .filter(|&(_,valve)| path.contains(&valve))
+                         .collect();
+        let map_elephant = map.iter()
+                              .filter(|&(_,valve)| !path.contains(&valve))
+                              .collect();
+
+        let pressure_you = pressure_from(&map_you, distances, start, time, &opened);
+        let pressure_elephant = pressure_from(&map_elephant, distances, start, time, &opened);
+        max_pressure = max_pressure.max(pressure_you + pressure_elephant);
+    }
+    max_pressure
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB",
+            "Valve BB has flow rate=13; tunnels lead to valves CC, AA",
+            "Valve CC has flow rate=2; tunnels lead to valves DD, BB",
+            "Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE",
+            "Valve EE has flow rate=3; tunnels lead to valves FF, DD",
+            "Valve FF has flow rate=0; tunnels lead to valves EE, GG",
+            "Valve GG has flow rate=0; tunnels lead to valves FF, HH",
+            "Valve HH has flow rate=22; tunnel leads to valve GG",
+            "Valve II has flow rate=0; tunnels lead to valves AA, JJ",
+            "Valve JJ has flow rate=21; tunnel leads to valve II",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+
+        let volcano = Volcano::new(&input);
+        assert_eq!(most_pressure(&volcano, false), 1651);
+        assert_eq!(most_pressure(&volcano, true), 1707);
+    }
+}
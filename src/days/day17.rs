@@ -0,0 +1,286 @@
+use crate::cycle;
+use crate::grid::Grid;
+use crate::{Answer, Error, Solution};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<String, Error> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(&self, jets: &String) -> Result<Answer, Error> {
+        Ok(u64::try_from(simulate_rocks(jets, 2022)).unwrap().into())
+    }
+
+    fn part2(&self, jets: &String) -> Result<Answer, Error> {
+        Ok(u64::try_from(simulate_rocks(jets, 1000000000000)).unwrap().into())
+    }
+}
+
+struct Map {
+    map: Grid<bool>,
+}
+
+impl Map {
+    fn new() -> Map {
+        Map { map: Grid::new(7, 0, false) }
+    }
+
+    fn height(&self) -> usize {
+        self.map.height()
+    }
+
+    fn occupied(&self, pos: &(usize, usize)) -> bool {
+        assert!(pos.0 <= 6);
+        if pos.1 >= self.height() {
+            return false;
+        }
+        self.map[*pos]
+    }
+
+    fn place(&mut self, pos: (usize, usize)) {
+        assert!(pos.0 <= 6);
+        while self.height() <= pos.1 {
+            self.map.push_row([false; 7]);
+        }
+        self.map[pos] = true;
+    }
+
+    /* depth of the highest rock in every column, relative to the top */
+    fn surface(&self) -> [usize; 7] {
+        std::array::from_fn(|x| self.map.column(x).rev().position(|&rock| rock).unwrap_or(self.height()))
+    }
+}
+
+#[derive(Debug)]
+struct Shape {
+    shape: u8,
+    pos: (usize, usize),  // top left position of the bounding box
+}
+
+impl Shape {
+    fn next(&self, map: &Map) -> Shape {
+        let next_shape = (self.shape + 1) % 5;
+        Shape::new(map, next_shape)
+    }
+
+    fn new(map: &Map, shape: u8) -> Shape {
+        let new_y = map.height() + 2 + match shape {
+            0 => 1,
+            1 => 3,
+            2 => 3,
+            3 => 4,
+            4 => 2,
+            _ => unimplemented!(),
+        };
+        Shape {
+            shape,
+            pos: (2, new_y),
+        }
+    }
+
+    fn can_move_down(&self, map: &Map) -> bool {
+        let (x,y) = self.pos;
+        match self.shape {
+            0 => y > 0 &&
+                 !map.occupied(&(x, y-1)) &&
+                 !map.occupied(&(x+1, y-1)) &&
+                 !map.occupied(&(x+2, y-1)) &&
+                 !map.occupied(&(x+3, y-1)),
+            1 => y > 2 &&
+                 !map.occupied(&(x, y-2)) &&
+                 !map.occupied(&(x+1, y-3)) &&
+                 !map.occupied(&(x+2, y-2)),
+            2 => y > 2 &&
+                 !map.occupied(&(x, y-3)) &&
+                 !map.occupied(&(x+1, y-3)) &&
+                 !map.occupied(&(x+2, y-3)),
+            3 => y > 3 &&
+                 !map.occupied(&(x, y-4)),
+            4 => y > 1 &&
+                 !map.occupied(&(x, y-2)) &&
+                 !map.occupied(&(x+1, y-2)),
+            _ => unimplemented!(),
+        }
+    }
+
+    fn can_move_left(&self, map: &Map) -> bool {
+        let (x,y) = self.pos;
+        if x == 0 {
+            return false;
+        }
+        match self.shape {
+            0 => !map.occupied(&(x-1, y)),
+            1 => !map.occupied(&(x, y)) &&
+                 !map.occupied(&(x-1, y-1)) &&
+                 !map.occupied(&(x, y-2)),
+            2 => !map.occupied(&(x+1, y)) &&
+                 !map.occupied(&(x+1, y-1)) &&
+                 !map.occupied(&(x-1, y-2)),
+            3 => !map.occupied(&(x-1, y)) &&
+                 !map.occupied(&(x-1, y-1)) &&
+                 !map.occupied(&(x-1, y-2)) &&
+                 !map.occupied(&(x-1, y-3)),
+            4 => !map.occupied(&(x-1, y)) &&
+                 !map.occupied(&(x-1, y-1)),
+            _ => unimplemented!(),
+        }
+    }
+
+    fn can_move_right(&self, map: &Map) -> bool {
+        let (x,y) = self.pos;
+        match self.shape {
+            0 => x < (7-4) &&
+                 !map.occupied(&(x+4, y)),
+            1 => x < (7-3) &&
+                 !map.occupied(&(x+2, y)) &&
+                 !map.occupied(&(x+3, y-1)) &&
+                 !map.occupied(&(x+2, y-2)),
+            2 => x < (7-3) &&
+                 !map.occupied(&(x+3, y)) &&
+                 !map.occupied(&(x+3, y-1)) &&
+                 !map.occupied(&(x+3, y-2)),
+            3 => x < (7-1) &&
+                 !map.occupied(&(x+1, y)) &&
+                 !map.occupied(&(x+1, y-1)) &&
+                 !map.occupied(&(x+1, y-2)) &&
+                 !map.occupied(&(x+1, y-3)),
+            4 => x < (7-2) &&
+                 !map.occupied(&(x+2, y)) &&
+                 !map.occupied(&(x+2, y-1)),
+            _ => unimplemented!(),
+        }
+    }
+
+    fn move_left(&mut self, map: &Map) {
+        if self.can_move_left(map) {
+            self.pos.0 -= 1;
+        }
+    }
+
+    fn move_right(&mut self, map: &Map) {
+        if self.can_move_right(map) {
+            self.pos.0 += 1;
+        }
+    }
+
+    fn move_down(&mut self) {
+        self.pos.1 -= 1;
+    }
+
+    fn movement(&mut self, map: &Map, direction: char) {
+        match direction {
+            '>' => self.move_right(map),
+            '<' => self.move_left(map),
+            _ => unimplemented!(),
+        }
+    }
+
+    fn add_to_map(&self, map: &mut Map) {
+        let (x, y) = self.pos;
+        match self.shape {
+            0 => {
+                map.place((x, y));
+                map.place((x+1, y));
+                map.place((x+2, y));
+                map.place((x+3, y));
+            },
+            1 => {
+                map.place((x+1, y));
+                map.place((x, y-1));
+                map.place((x+1, y-1));
+                map.place((x+2, y-1));
+                map.place((x+1, y-2));
+            },
+            2 => {
+                map.place((x+2, y));
+                map.place((x+2, y-1));
+                map.place((x+2, y-2));
+                map.place((x+1, y-2));
+                map.place((x, y-2));
+            },
+            3 => {
+                map.place((x, y));
+                map.place((x, y-1));
+                map.place((x, y-2));
+                map.place((x, y-3));
+            },
+            4 => {
+                map.place((x, y));
+                map.place((x+1, y));
+                map.place((x, y-1));
+                map.place((x+1, y-1));
+            }
+            _ => unimplemented!(),
+        }
+    }
+}
+
+struct Tetris {
+    map: Map,  // positions occupied by rocks
+    rock: Shape,
+    jets: Vec<char>,
+    jet: usize,  // index of the next jet
+}
+
+impl Tetris {
+    fn new(jets: &str) -> Tetris {
+        let map = Map::new();
+        let rock = Shape::new(&map, 0);
+        Tetris {
+            map,
+            rock,
+            jets: jets.chars().collect(),
+            jet: 0,
+        }
+    }
+
+    /* moves the current rock until it comes to rest */
+    fn drop_rock(&mut self) {
+        loop {
+            let direction = self.jets[self.jet];
+            self.jet = (self.jet + 1) % self.jets.len();
+            self.rock.movement(&self.map, direction);
+            if self.rock.can_move_down(&self.map) {
+                self.rock.move_down();
+            } else {
+                self.rock.add_to_map(&mut self.map);
+                self.rock = self.rock.next(&self.map);
+                return;
+            }
+        }
+    }
+
+    /* everything the next rocks depend on: the rock, the jet and the shape of the surface */
+    fn state(&self) -> (u8, usize, [usize; 7]) {
+        (self.rock.shape, self.jet, self.map.surface())
+    }
+}
+
+fn simulate_rocks(input: &str, max_rocks: u64) -> u128 {
+    let mut tetris = Tetris::new(input);
+    let steps = std::iter::from_fn(|| {
+        let state = tetris.state();
+        let height = tetris.map.height();
+        tetris.drop_rock();
+        Some((state, (tetris.map.height() - height) as u64))
+    });
+    cycle::detect(steps).expect("rocks should fall in a cycle")
+                        .value_after(max_rocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+        assert_eq!(simulate_rocks(input, 2022), 3068);
+        assert_eq!(simulate_rocks(input, 1000000000000), 1514285714288);
+    }
+}
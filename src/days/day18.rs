@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+use crate::geom::Point3;
+use crate::{lines, Answer, Error, Solution};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Point3>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Point3>, Error> {
+        Ok(parse_cubes(&lines(input)))
+    }
+
+    fn part1(&self, cubes: &Vec<Point3>) -> Result<Answer, Error> {
+        Ok(surface_area(cubes).into())
+    }
+
+    fn part2(&self, cubes: &Vec<Point3>) -> Result<Answer, Error> {
+        Ok(exterior_surface_area(cubes).into())
+    }
+}
+
+fn parse_cube(input: &str) -> Point3 {
+    let mut coords = input.split(',');
+    let x = coords.next().unwrap().parse().unwrap();
+    let y = coords.next().unwrap().parse().unwrap();
+    let z = coords.next().unwrap().parse().unwrap();
+    Point3 { x, y, z }
+}
+
+fn unconnected_surfaces(cube: &Point3, cubes: &[Point3]) -> usize {
+    6 - cubes.iter()
+             .filter(|&c| c.manhattan(cube) == 1)
+             .count()
+}
+
+fn parse_cubes(input: &[String]) -> Vec<Point3> {
+    input.iter()
+         .map(|x| parse_cube(x))
+         .collect()
+}
+
+fn surface_area(cubes: &[Point3]) -> usize {
+    cubes.iter()
+         .map(|c| unconnected_surfaces(c, cubes))
+         .sum()
+}
+
+fn find_outer_air(air: &mut HashSet<Point3>, cubes: &HashSet<Point3>, pos: &Point3, min: &Point3, max: &Point3) {
+    if cubes.contains(pos) || air.contains(pos) {
+        return;
+    }
+    air.insert(*pos);
+    for neigh in pos.neighbors6() {
+        if neigh.x < min.x || neigh.y < min.y || neigh.z < min.z || neigh.x > max.x || neigh.y > max.y || neigh.z > max.z {
+            continue;
+        }
+        find_outer_air(air, cubes, &neigh, min, max);
+    }
+}
+
+fn exterior_surface_area(cubes: &[Point3]) -> usize {
+    let cubes = cubes.iter()
+                     .copied()
+                     .collect::<HashSet<_>>();
+    let min_x = cubes.iter().map(|c| c.x).min().unwrap();
+    let min_y = cubes.iter().map(|c| c.y).min().unwrap();
+    let min_z = cubes.iter().map(|c| c.z).min().unwrap();
+    let min_cube = Point3::new(min_x - 1, min_y - 1, min_z - 1);
+    let max_x = cubes.iter().map(|c| c.x).max().unwrap();
+    let max_y = cubes.iter().map(|c| c.y).max().unwrap();
+    let max_z = cubes.iter().map(|c| c.z).max().unwrap();
+    let max_cube = Point3::new(max_x + 1, max_y + 1, max_z + 1);
+
+    let mut air = HashSet::new();
+    let start = Point3::default();
+    assert!(!cubes.contains(&start));
+    find_outer_air(&mut air, &cubes, &start, &min_cube, &max_cube);
+
+    let mut surfaces = 0;
+    for air_cube in air {
+        surfaces += cubes.iter()
+                         .filter(|c| c.manhattan(&air_cube) == 1)
+                         .count();
+    }
+
+    surfaces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "2,2,2",
+            "1,2,2",
+            "3,2,2",
+            "2,1,2",
+            "2,3,2",
+            "2,2,1",
+            "2,2,3",
+            "2,2,4",
+            "2,2,6",
+            "1,2,5",
+            "3,2,5",
+            "2,1,5",
+            "2,3,5",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+
+        let cubes = parse_cubes(&input);
+        assert_eq!(surface_area(&cubes), 64);
+        assert_eq!(exterior_surface_area(&cubes), 58);
+    }
+}
@@ -0,0 +1,147 @@
+use regex::Regex;
+use crate::{lines, Answer, Error, Solution};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Blueprint>, Error> {
+        Ok(parse_blueprints(&lines(input)))
+    }
+
+    fn part1(&self, blueprints: &Vec<Blueprint>) -> Result<Answer, Error> {
+        Ok(quality_levels(blueprints).into())
+    }
+
+    fn part2(&self, blueprints: &Vec<Blueprint>) -> Result<Answer, Error> {
+        Ok(geodes_product(blueprints).into())
+    }
+}
+
+/* resources, also used as index of the robot collecting them */
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+pub struct Blueprint {
+    id: u32,
+    costs: [[u32; 4]; 4],  // costs[robot][resource]
+}
+
+impl Blueprint {
+    fn new(input: &str) -> Blueprint {
+        let re = Regex::new("Blueprint ([0-9]+): Each ore robot costs ([0-9]+) ore. Each clay robot costs ([0-9]+) ore. Each obsidian robot costs ([0-9]+) ore and ([0-9]+) clay. Each geode robot costs ([0-9]+) ore and ([0-9]+) obsidian.").unwrap();
+
+        let caps = re.captures(input).expect("input should match regex");
+        let n = |i: usize| caps[i].parse::<u32>().unwrap();
+        let mut costs = [[0; 4]; 4];
+        costs[ORE][ORE] = n(2);
+        costs[CLAY][ORE] = n(3);
+        costs[OBSIDIAN][ORE] = n(4);
+        costs[OBSIDIAN][CLAY] = n(5);
+        costs[GEODE][ORE] = n(6);
+        costs[GEODE][OBSIDIAN] = n(7);
+
+        Blueprint { id: n(1), costs }
+    }
+
+    /* minutes to wait until the robot is affordable, None if it never will be */
+    fn waiting_time(&self, robot: usize, robots: &[u32; 4], resources: &[u32; 4]) -> Option<u32> {
+        let mut wait = 0;
+        for resource in [ORE, CLAY, OBSIDIAN] {
+            let cost = self.costs[robot][resource];
+            if cost <= resources[resource] {
+                continue;
+            }
+            if robots[resource] == 0 {
+                return None;
+            }
+            wait = wait.max((cost - resources[resource]).div_ceil(robots[resource]));
+        }
+        Some(wait)
+    }
+
+    fn search(&self, max_robots: &[u32; 4], time: u32, robots: [u32; 4], resources: [u32; 4], best: &mut u32) {
+        /* geodes at the end if no more robots get built */
+        let geodes = resources[GEODE] + robots[GEODE] * time;
+        *best = (*best).max(geodes);
+        /* even a new geode robot every minute can't beat the best */
+        if geodes + time * time.saturating_sub(1) / 2 <= *best {
+            return;
+        }
+
+        /* instead of simulating every minute, decide which robot to build next and skip ahead */
+        for robot in [GEODE, OBSIDIAN, CLAY, ORE] {
+            if robots[robot] >= max_robots[robot] {
+                /* more robots than can be spent per minute are useless */
+                continue;
+            }
+            let wait = match self.waiting_time(robot, &robots, &resources) {
+                Some(wait) if wait + 1 < time => wait,
+                _ => continue,
+            };
+
+            let mut next_robots = robots;
+            let mut next_resources = resources;
+            for resource in 0 .. 4 {
+                next_resources[resource] += robots[resource] * (wait + 1);
+                next_resources[resource] -= self.costs[robot][resource];
+            }
+            next_robots[robot] += 1;
+            self.search(max_robots, time - wait - 1, next_robots, next_resources, best);
+        }
+    }
+
+    fn max_geodes(&self, minutes: u32) -> u32 {
+        let mut max_robots = [u32::MAX; 4];
+        for resource in [ORE, CLAY, OBSIDIAN] {
+            max_robots[resource] = self.costs.iter().map(|c| c[resource]).max().unwrap();
+        }
+
+        let mut best = 0;
+        self.search(&max_robots, minutes, [1, 0, 0, 0], [0; 4], &mut best);
+        best
+    }
+}
+
+fn parse_blueprints(input: &[String]) -> Vec<Blueprint> {
+    input.iter()
+         .map(|x| Blueprint::new(x))
+         .collect()
+}
+
+fn quality_levels(blueprints: &[Blueprint]) -> u32 {
+    blueprints.iter()
+              .map(|b| b.id * b.max_geodes(24))
+              .sum()
+}
+
+fn geodes_product(blueprints: &[Blueprint]) -> u32 {
+    blueprints.iter()
+              .take(3)
+              .map(|b| b.max_geodes(32))
+              .product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.",
+            "Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+
+        let blueprints = parse_blueprints(&input);
+        assert_eq!(blueprints[0].max_geodes(24), 9);
+        assert_eq!(blueprints[1].max_geodes(24), 12);
+        assert_eq!(quality_levels(&blueprints), 33);
+        assert_eq!(blueprints[0].max_geodes(32), 56);
+        assert_eq!(blueprints[1].max_geodes(32), 62);
+        assert_eq!(geodes_product(&blueprints), 56 * 62);
+    }
+}
@@ -0,0 +1,607 @@
+use std::fmt;
+use itertools::Itertools;
+use crate::{lines, Answer, Error, Solution};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<(usize, usize)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        parse_guide(&lines(input), &Game::rock_paper_scissors()).map_err(Error::invalid)
+    }
+
+    fn part1(&self, guide: &Self::Input) -> Result<Answer, Error> {
+        Ok(game_score(&Game::rock_paper_scissors(), guide, false).into())
+    }
+
+    fn part2(&self, guide: &Self::Input) -> Result<Answer, Error> {
+        Ok(game_score(&Game::rock_paper_scissors(), guide, true).into())
+    }
+}
+
+/// A shape of a [`Game`], identified by its position in the definition.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Shape(pub usize);
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+/// A hand game described by data: its shapes, which shape beats which,
+/// and the scores for the chosen shape and the outcome of a round.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    names: Vec<String>,
+    scores: Vec<u32>,
+    beats: Vec<Vec<bool>>,  // beats[a][b]: shape a wins against shape b
+    outcome_scores: [u32; 3],  // for loss, draw and win
+}
+
+impl Game {
+    /// Game with an odd number of shapes in which every shape beats
+    /// the half of the other shapes preceding it (cyclically).
+    /// Shapes score 1, 2, 3, ... in the given order.
+    pub fn cyclic(names: &[&str]) -> Game {
+        let n = names.len();
+        assert!(n % 2 == 1 && n >= 3, "cyclic games need an odd number of at least three shapes");
+        let beats = (0 .. n).map(|a| (0 .. n).map(|b| a != b && (a + n - b) % n <= n / 2).collect())
+                            .collect();
+        Game {
+            names: names.iter().map(|&name| String::from(name)).collect(),
+            scores: (1 ..= n as u32).collect(),
+            beats,
+            outcome_scores: [0, 3, 6],
+        }
+    }
+
+    pub fn rock_paper_scissors() -> Game {
+        Game::cyclic(&["Rock", "Paper", "Scissors"])
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> Game {
+        Game::cyclic(&["Rock", "Spock", "Paper", "Lizard", "Scissors"])
+    }
+
+    /// Reads a game definition with one statement per line:
+    /// `shape NAME SCORE`, `beats WINNER LOSER` and `outcome LOSS DRAW WIN`.
+    /// Every pair of different shapes needs exactly one winner, and every shape
+    /// needs to win and lose against some other, so there are at least 3.
+    pub fn parse(input: &str) -> Result<Game, String> {
+        let mut names = Vec::new();
+        /* where every shape is defined, for the errors */
+        let mut lines = Vec::new();
+        let mut scores = Vec::new();
+        let mut wins = Vec::new();
+        let mut outcome_scores = [0, 3, 6];
+
+        for (idx, line) in input.lines().enumerate() {
+            let words = line.split_whitespace().collect::<Vec<_>>();
+            let number = |word: &str| word.parse::<u32>().map_err(|e| format!("line {}: {}", idx + 1, e));
+            match words[..] {
+                [] => continue,
+                ["shape", name, score] => {
+                    names.push(name.to_string());
+                    scores.push(number(score)?);
+                    lines.push(idx + 1);
+                },
+                ["beats", winner, loser] => wins.push((winner, loser, idx + 1)),
+                ["outcome", loss, draw, win] => outcome_scores = [number(loss)?, number(draw)?, number(win)?],
+                _ => return Err(format!("line {}: invalid statement: {:?}", idx + 1, line)),
+            }
+        }
+
+        if names.len() < 3 {
+            return Err(format!("line {}: a game needs at least 3 shapes, not {}", input.lines().count().max(1), names.len()));
+        }
+
+        let shape = |name: &str, line: usize| names.iter()
+                                                   .position(|n| n == name)
+                                                   .ok_or_else(|| format!("line {}: unknown shape: {}", line, name));
+        let mut beats = vec![vec![false; names.len()]; names.len()];
+        for (winner, loser, line) in wins {
+            beats[shape(winner, line)?][shape(loser, line)?] = true;
+        }
+        for a in 0 .. names.len() {
+            for b in a .. names.len() {
+                let winners = beats[a][b] as usize + beats[b][a] as usize;
+                if (a == b && winners > 0) || (a != b && winners != 1) {
+                    return Err(format!("line {}: {} against {} needs exactly one winner", lines[b], names[a], names[b]));
+                }
+            }
+        }
+        /* otherwise there is no shape to win or lose against some shape */
+        for a in 0 .. names.len() {
+            if !beats[a].contains(&true) || !beats.iter().any(|row| row[a]) {
+                return Err(format!("line {}: {} needs to beat a shape and to lose against one", lines[a], names[a]));
+            }
+        }
+
+        Ok(Game { names, scores, beats, outcome_scores })
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0 .. self.len()).map(Shape)
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.names[shape.0]
+    }
+
+    pub fn outcome(&self, you: Shape, opponent: Shape) -> Outcome {
+        if self.beats[you.0][opponent.0] {
+            Outcome::Win
+        } else if self.beats[opponent.0][you.0] {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// A shape leading to `outcome` against `opponent`, the first one if there are several.
+    /// Every game has one, as each shape beats and loses against some shape.
+    pub fn shape_for(&self, opponent: Shape, outcome: Outcome) -> Shape {
+        self.shapes()
+            .find(|&you| self.outcome(you, opponent) == outcome)
+            .expect("every outcome should be possible against every shape")
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> u32 {
+        self.outcome_scores[outcome as usize]
+    }
+
+    pub fn shape_score(&self, shape: Shape) -> u32 {
+        self.scores[shape.0]
+    }
+}
+
+/// Meaning of the second column of the strategy guide.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Interpretation {
+    /// The shape to play for X, Y, Z, ...
+    Shapes(Vec<Shape>),
+    /// X, Y and Z tell whether to lose, draw or win.
+    Result,
+}
+
+/* the second column starts at X and continues with A after Z */
+fn column_letter(column: usize) -> char {
+    (b'A' + ((b'X' - b'A') as usize + column) as u8 % 26) as char
+}
+
+impl Interpretation {
+    fn shape(&self, game: &Game, opponent: Shape, column: usize) -> Shape {
+        match self {
+            Interpretation::Shapes(shapes) => shapes[column],
+            Interpretation::Result => {
+                let outcome = match column {
+                    0 => Outcome::Loss,
+                    1 => Outcome::Draw,
+                    2 => Outcome::Win,
+                    _ => panic!("column {} has no outcome", column_letter(column)),
+                };
+                game.shape_for(opponent, outcome)
+            },
+        }
+    }
+
+    fn describe(&self, game: &Game) -> String {
+        match self {
+            Interpretation::Shapes(shapes) => shapes.iter()
+                                                    .enumerate()
+                                                    .map(|(column, &shape)| format!("{}={}", column_letter(column), game.name(shape)))
+                                                    .join(" "),
+            Interpretation::Result => String::from("X=lose Y=draw Z=win"),
+        }
+    }
+}
+
+struct Round {
+    opponent: Shape,
+    you: Shape,
+}
+
+impl Round {
+    fn new(game: &Game, &(opponent, column): &(usize, usize), interpretation: &Interpretation) -> Round {
+        let opponent = Shape(opponent);
+        assert!(opponent.0 < game.len(), "guide should only use shapes of the game");
+        let you = interpretation.shape(game, opponent, column);
+
+        Round { opponent, you }
+    }
+
+    fn outcome_score(&self, game: &Game) -> u32 {
+        game.outcome_score(game.outcome(self.you, self.opponent))
+    }
+
+    fn score(&self, game: &Game) -> u32 {
+        self.outcome_score(game) + game.shape_score(self.you)
+    }
+}
+
+/// Reads the guide as `(opponent, column)` pairs, checking that both letters name shapes of `game`.
+/// The opponent's letters count from A, the second column from X and continues with A after Z.
+pub fn parse_guide(input: &[String], game: &Game) -> Result<Vec<(usize, usize)>, String> {
+    input.iter()
+         .enumerate()
+         .map(|(idx, line)| {
+             let (opponent, column) = match line.chars().collect::<Vec<_>>()[..] {
+                 [opponent, ' ', column] if opponent.is_ascii_uppercase() && column.is_ascii_uppercase() => (opponent, column),
+                 _ => return Err(format!("line {}: invalid round: {:?}", idx + 1, line)),
+             };
+             let opponent = (opponent as u8 - b'A') as usize;
+             let column = (column as u8 - b'A' + 26 - (b'X' - b'A')) as usize % 26;
+             if opponent >= game.len() || column >= game.len() {
+                 return Err(format!("line {}: {:?} is outside the {} shapes of the game", idx + 1, line, game.len()));
+             }
+             Ok((opponent, column))
+         })
+         .collect()
+}
+
+fn game_score(game: &Game, guide: &[(usize, usize)], result_indicator: bool) -> u32 {
+    let interpretation = if result_indicator {
+        Interpretation::Result
+    } else {
+        Interpretation::Shapes(game.shapes().collect())
+    };
+    score_guide(game, guide, &interpretation).score
+}
+
+/// Scores of a strategy guide under one interpretation.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Analysis {
+    pub interpretation: Interpretation,
+    pub score: u32,
+    /// Outcome and score of every round.
+    pub rounds: Vec<(Outcome, u32)>,
+}
+
+impl Analysis {
+    pub fn count(&self, outcome: Outcome) -> usize {
+        self.rounds.iter().filter(|&&(o, _)| o == outcome).count()
+    }
+}
+
+pub fn score_guide(game: &Game, guide: &[(usize, usize)], interpretation: &Interpretation) -> Analysis {
+    let rounds = guide.iter()
+                      .map(|x| Round::new(game, x, interpretation))
+                      .map(|x| (game.outcome(x.you, x.opponent), x.score(game)))
+                      .collect::<Vec<_>>();
+    let score = rounds.iter().map(|&(_, score)| score).sum();
+    Analysis { interpretation: interpretation.clone(), score, rounds }
+}
+
+/// Scores the guide under every assignment of shapes to the letters its second
+/// column uses and under the result interpretation, best first.
+pub fn analyze(game: &Game, guide: &[(usize, usize)]) -> Vec<Analysis> {
+    /* letters past the last one in the guide would only repeat the same scores */
+    let columns = guide.iter().map(|&(_, column)| column + 1).max().unwrap_or(0);
+    let mut analyses = game.shapes()
+                           .permutations(columns)
+                           .map(Interpretation::Shapes)
+                           /* only X, Y and Z have a meaning as results */
+                           .chain(guide.iter().all(|&(_, column)| column < 3).then_some(Interpretation::Result))
+                           .map(|interpretation| score_guide(game, guide, &interpretation))
+                           .collect::<Vec<_>>();
+    analyses.sort_by_key(|a| std::cmp::Reverse(a.score));
+    analyses
+}
+
+/// Table of all interpretations of the guide, with the best and worst one.
+pub struct Report<'a> {
+    pub game: &'a Game,
+    pub analyses: Vec<Analysis>,
+}
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let describe = |a: &Analysis| a.interpretation.describe(self.game);
+        let width = self.analyses.iter().map(|a| describe(a).len()).max().unwrap_or(0);
+        writeln!(f, "{:<width$}  {:>8}  {:>6}  {:>6}  {:>6}", "interpretation", "score", "wins", "draws", "losses")?;
+        for a in &self.analyses {
+            writeln!(f, "{:<width$}  {:>8}  {:>6}  {:>6}  {:>6}", describe(a), a.score,
+                     a.count(Outcome::Win), a.count(Outcome::Draw), a.count(Outcome::Loss))?;
+        }
+        if let (Some(best), Some(worst)) = (self.analyses.first(), self.analyses.last()) {
+            writeln!(f, "best: {} ({})", describe(best), best.score)?;
+            writeln!(f, "worst: {} ({})", describe(worst), worst.score)?;
+        }
+        Ok(())
+    }
+}
+
+/// Small seeded pseudo random number generator (xorshift64*), so that
+/// tournaments can be repeated.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        /* the state must never be zero */
+        Rng { state: seed ^ 0x9e37_79b9_7f4a_7c15 | 1 }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Number in `0 .. n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
+
+/// One round of a match, from the point of view of the player choosing next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Played {
+    pub own: Shape,
+    pub opponent: Shape,
+}
+
+/// A player of a match, choosing its shape from the rounds played so far.
+pub trait Strategy {
+    fn name(&self, game: &Game) -> String;
+    fn choose(&self, game: &Game, history: &[Played], rng: &mut Rng) -> Shape;
+}
+
+/// Always plays the same shape.
+pub struct Always(pub Shape);
+
+impl Strategy for Always {
+    fn name(&self, game: &Game) -> String {
+        format!("always-{}", game.name(self.0).to_lowercase())
+    }
+
+    fn choose(&self, _game: &Game, _history: &[Played], _rng: &mut Rng) -> Shape {
+        self.0
+    }
+}
+
+/// Plays all shapes in turn.
+pub struct Cycle;
+
+impl Strategy for Cycle {
+    fn name(&self, _game: &Game) -> String {
+        String::from("cycle")
+    }
+
+    fn choose(&self, game: &Game, history: &[Played], _rng: &mut Rng) -> Shape {
+        Shape(history.len() % game.len())
+    }
+}
+
+/// Beats the shape the opponent played most often so far.
+pub struct FrequencyCounter;
+
+impl Strategy for FrequencyCounter {
+    fn name(&self, _game: &Game) -> String {
+        String::from("frequency-counter")
+    }
+
+    fn choose(&self, game: &Game, history: &[Played], rng: &mut Rng) -> Shape {
+        let mut counts = vec![0; game.len()];
+        for played in history {
+            counts[played.opponent.0] += 1;
+        }
+        let most = counts.iter().max().copied().unwrap_or(0);
+        if most == 0 {
+            return Shape(rng.below(game.len()));
+        }
+        /* ties are broken randomly, so that it can't be exploited that easily */
+        let candidates = game.shapes().filter(|s| counts[s.0] == most).collect::<Vec<_>>();
+        let expected = candidates[rng.below(candidates.len())];
+        game.shape_for(expected, Outcome::Win)
+    }
+}
+
+/// Keeps the shape after a win, otherwise moves on to the next one.
+pub struct WinStayLoseShift;
+
+impl Strategy for WinStayLoseShift {
+    fn name(&self, _game: &Game) -> String {
+        String::from("win-stay-lose-shift")
+    }
+
+    fn choose(&self, game: &Game, history: &[Played], rng: &mut Rng) -> Shape {
+        match history.last() {
+            None => Shape(rng.below(game.len())),
+            Some(last) if game.outcome(last.own, last.opponent) == Outcome::Win => last.own,
+            Some(last) => Shape((last.own.0 + 1) % game.len()),
+        }
+    }
+}
+
+/// Plays the shapes of the strategy guide, starting over at its end.
+pub struct ReplayGuide(pub Vec<Shape>);
+
+impl Strategy for ReplayGuide {
+    fn name(&self, _game: &Game) -> String {
+        String::from("replay-guide")
+    }
+
+    fn choose(&self, _game: &Game, history: &[Played], _rng: &mut Rng) -> Shape {
+        self.0[history.len() % self.0.len()]
+    }
+}
+
+/// Picks any shape at random.
+pub struct Random;
+
+impl Strategy for Random {
+    fn name(&self, _game: &Game) -> String {
+        String::from("random")
+    }
+
+    fn choose(&self, game: &Game, _history: &[Played], rng: &mut Rng) -> Shape {
+        Shape(rng.below(game.len()))
+    }
+}
+
+/// The built-in strategies, with the guide's second column played as shapes.
+pub fn builtin_strategies(game: &Game, guide: &[(usize, usize)]) -> Vec<Box<dyn Strategy>> {
+    let mut strategies: Vec<Box<dyn Strategy>> = vec![
+        Box::new(Always(Shape(0))),
+        Box::new(Cycle),
+        Box::new(FrequencyCounter),
+        Box::new(WinStayLoseShift),
+        Box::new(Random),
+    ];
+    if !guide.is_empty() {
+        strategies.push(Box::new(ReplayGuide(guide.iter().map(|&(_, column)| Shape(column % game.len())).collect())));
+    }
+    strategies
+}
+
+/// Outcome of a round-robin tournament.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Tournament {
+    pub names: Vec<String>,
+    /// Total score of every strategy over all of its matches.
+    pub scores: Vec<u64>,
+    /// wins[a][b]: rounds strategy a won against strategy b.
+    pub wins: Vec<Vec<usize>>,
+}
+
+/// Lets every strategy play `rounds` rounds against every other one.
+pub fn tournament(game: &Game, strategies: &[Box<dyn Strategy>], rounds: usize, seed: u64) -> Tournament {
+    let n = strategies.len();
+    let mut rng = Rng::new(seed);
+    let mut scores = vec![0; n];
+    let mut wins = vec![vec![0; n]; n];
+
+    for a in 0 .. n {
+        for b in a + 1 .. n {
+            let mut history_a = Vec::new();
+            let mut history_b = Vec::new();
+            for _ in 0 .. rounds {
+                let shape_a = strategies[a].choose(game, &history_a, &mut rng);
+                let shape_b = strategies[b].choose(game, &history_b, &mut rng);
+                for (player, own, opponent) in [(a, shape_a, shape_b), (b, shape_b, shape_a)] {
+                    let outcome = game.outcome(own, opponent);
+                    scores[player] += (game.shape_score(own) + game.outcome_score(outcome)) as u64;
+                    if outcome == Outcome::Win {
+                        wins[player][if player == a { b } else { a }] += 1;
+                    }
+                }
+                history_a.push(Played { own: shape_a, opponent: shape_b });
+                history_b.push(Played { own: shape_b, opponent: shape_a });
+            }
+        }
+    }
+
+    Tournament { names: strategies.iter().map(|s| s.name(game)).collect(), scores, wins }
+}
+
+impl fmt::Display for Tournament {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        /* the numbered columns are the rounds won against the strategy with that number */
+        let width = self.names.iter().map(String::len).max().unwrap_or(0);
+        let numbers = (1 ..= self.names.len()).map(|i| format!("{:>6}", i)).join("");
+        writeln!(f, "{:>2}  {:<width$}  {:>10}{}", "#", "strategy", "score", numbers)?;
+        for (i, name) in self.names.iter().enumerate() {
+            let wins = self.wins[i].iter()
+                                   .enumerate()
+                                   .map(|(j, w)| if i == j { format!("{:>6}", "-") } else { format!("{:>6}", w) })
+                                   .join("");
+            writeln!(f, "{:>2}  {:<width$}  {:>10}{}", i + 1, name, self.scores[i], wins)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "A Y".to_string(),
+            "B X".to_string(),
+            "C Z".to_string(),
+        ];
+        let game = Game::rock_paper_scissors();
+        let guide = parse_guide(&input, &game).unwrap();
+        assert_eq!(parse_guide(&["A W".to_string()], &game), Err("line 1: \"A W\" is outside the 3 shapes of the game".to_string()));
+        assert_eq!(parse_guide(&["D X".to_string()], &game), Err("line 1: \"D X\" is outside the 3 shapes of the game".to_string()));
+        assert!(parse_guide(&["AX".to_string()], &game).is_err());
+        assert!(parse_guide(&["a x".to_string()], &game).is_err());
+        assert_eq!(game_score(&game, &guide, false), 15);
+        assert_eq!(game_score(&game, &guide, true), 12);
+
+        let analyses = analyze(&game, &guide);
+        assert_eq!(analyses.len(), 7);
+        assert_eq!(analyses.iter().map(|a| a.score).collect::<Vec<_>>(), [24, 15, 15, 15, 15, 12, 6]);
+        let best = &analyses[0];
+        assert_eq!(best.interpretation.describe(&game), "X=Scissors Y=Paper Z=Rock");
+        assert_eq!((best.count(Outcome::Win), best.count(Outcome::Draw), best.count(Outcome::Loss)), (3, 0, 0));
+        let result = analyses.iter().find(|a| a.interpretation == Interpretation::Result).unwrap();
+        assert_eq!(result.rounds, [(Outcome::Draw, 4), (Outcome::Loss, 1), (Outcome::Win, 7)]);
+        let report = Report { game: &game, analyses }.to_string();
+        assert!(report.ends_with("best: X=Scissors Y=Paper Z=Rock (24)\nworst: X=Rock Y=Scissors Z=Paper (6)\n"));
+
+        let strategies = builtin_strategies(&game, &guide);
+        let names = strategies.iter().map(|s| s.name(&game)).collect::<Vec<_>>();
+        assert_eq!(names, ["always-rock", "cycle", "frequency-counter", "win-stay-lose-shift", "random", "replay-guide"]);
+        let result = tournament(&game, &strategies, 100, 1);
+        assert_eq!(result, tournament(&game, &strategies, 100, 1));
+        /* cycle draws, wins and loses against always-rock in turn */
+        assert_eq!((result.wins[1][0], result.wins[0][1]), (33, 33));
+        /* win-stay-lose-shift keeps paper once it beat always-rock */
+        assert!(result.wins[3][0] >= 98);
+        /* frequency-counter learns to beat always-rock right after the first round */
+        assert!(result.wins[2][0] >= 99);
+        assert_eq!(result.to_string().lines().count(), strategies.len() + 1);
+        let mut rng = Rng::new(7);
+        assert!((0 .. 100).all(|_| rng.below(3) < 3));
+
+        let definition = "shape Rock 1\nshape Paper 2\nshape Scissors 3\n\
+                          beats Paper Rock\nbeats Scissors Paper\nbeats Rock Scissors\noutcome 0 3 6\n";
+        assert_eq!(Game::parse(definition), Ok(game));
+        assert!(Game::parse("shape Rock 1\nbeats Rock Paper\n").is_err());
+        assert_eq!(Game::parse(""), Err("line 1: a game needs at least 3 shapes, not 0".to_string()));
+        assert_eq!(Game::parse("shape Rock 1\nshape Paper 2\nbeats Paper Rock\n"),
+                   Err("line 3: a game needs at least 3 shapes, not 2".to_string()));
+        assert_eq!(Game::parse("shape Rock 1\nshape Paper 2\nshape Scissors 3\nbeats Paper Rock\nbeats Scissors Rock\nbeats Scissors Paper\n"),
+                   Err("line 1: Rock needs to beat a shape and to lose against one".to_string()));
+        assert_eq!(Game::parse("shape Rock 1\nshape Paper 2\nshape Scissors 3\nbeats Paper Rock\n"),
+                   Err("line 3: Rock against Scissors needs exactly one winner".to_string()));
+
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let [rock, spock, paper, lizard, scissors] = ["Rock", "Spock", "Paper", "Lizard", "Scissors"].map(|name| {
+            game.shapes().find(|&s| game.name(s) == name).unwrap()
+        });
+        assert_eq!(game.outcome(paper, spock), Outcome::Win);
+        assert_eq!(game.outcome(lizard, scissors), Outcome::Loss);
+        assert_eq!(game.outcome(rock, rock), Outcome::Draw);
+        assert_eq!(game.shape_for(lizard, Outcome::Win), rock);
+        /* Spock beats Rock, loses against Lizard, Lizard loses against Scissors */
+        let input = [
+            "A Y".to_string(),
+            "D Y".to_string(),
+            "E A".to_string(),
+        ];
+        assert_eq!(game_score(&game, &parse_guide(&input, &game).unwrap(), false), 8 + 2 + 4);
+        /* X, Y and Z take 5 * 4 * 3 different shapes, plus the result interpretation */
+        let guide = parse_guide(&["A Y".to_string(), "B Z".to_string()], &game).unwrap();
+        let analyses = analyze(&game, &guide);
+        assert_eq!(analyses.len(), 60 + 1);
+        assert!(analyses.iter().map(|a| a.interpretation.describe(&game)).all_unique());
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{lines, Answer, Error, Solution};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<isize>;
+
+    fn parse(&self, input: &str) -> Result<Vec<isize>, Error> {
+        Ok(parse_numbers(&lines(input)))
+    }
+
+    fn part1(&self, numbers: &Vec<isize>) -> Result<Answer, Error> {
+        Ok(grove_coordinates(numbers, false).into())
+    }
+
+    fn part2(&self, numbers: &Vec<isize>) -> Result<Answer, Error> {
+        Ok(grove_coordinates(numbers, true).into())
+    }
+}
+
+fn parse_numbers(input: &[String]) -> Vec<isize> {
+    input.iter()
+         .map(|x| x.parse::<isize>().unwrap())
+         .collect()
+}
+
+fn grove_coordinates(numbers: &[isize], full_decryption: bool) -> isize {
+    let (decryption_key, rounds) = if full_decryption { (811589153, 10) } else { (1, 1) };
+
+    let mut input = numbers.iter()
+                           .enumerate()
+                           .map(|(idx,x)| (idx, x * decryption_key))
+                           .collect::<Vec<_>>();
+
+    for _ in 0 .. rounds {
+        for idx in 0 .. input.len() {
+            let pos = input.iter().position(|x| x.0 == idx).unwrap();
+            let val = input[pos].1;
+            input.remove(pos);
+
+            let input_len = input.len() as isize;
+
+            let pos_diff = val % input_len;
+            let mut new_pos = (pos as isize + pos_diff) % input_len;
+            if new_pos <= 0 {
+                new_pos += input_len;
+            }
+
+            input.insert(new_pos as usize, (idx, val));
+        }
+    }
+
+    let pos = input.iter().position(|x| x.1 == 0).unwrap();
+    let n = ((pos + 1000) % input.len(), (pos + 2000) % input.len(), (pos + 3000) % input.len());
+    input[n.0].1 + input[n.1].1 + input[n.2].1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "1",
+            "2",
+            "-3",
+            "3",
+            "-2",
+            "0",
+            "4",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+
+        let numbers = parse_numbers(&input);
+        assert_eq!(grove_coordinates(&numbers, false), 3);
+        assert_eq!(grove_coordinates(&numbers, true), 1623178306);
+    }
+}
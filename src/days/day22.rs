@@ -0,0 +1,284 @@
+use crate::geom::{Dir4, Point2};
+use crate::grid::Grid;
+use crate::{lines, Answer, Error, Solution};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Map, Error> {
+        Ok(Map::new(&lines(input), false))
+    }
+
+    fn part1(&self, map: &Map) -> Result<Answer, Error> {
+        Ok(find_password(map, false).into())
+    }
+
+    fn part2(&self, map: &Map) -> Result<Answer, Error> {
+        Ok(find_password(map, true).into())
+    }
+}
+
+#[derive(Clone,Copy)]
+enum Object {
+    Floor,
+    Wall,
+}
+
+#[derive(Clone)]
+enum Instruction {
+    Left,
+    Right,
+    Move { amount: isize },
+}
+
+#[derive(Clone)]
+pub struct Map {
+    map: Grid<Option<Object>>,
+    instructions: Vec<Instruction>,
+    pos: Point2,
+    direction: Dir4,
+    cube_wrapping: bool,
+}
+
+impl Map {
+    fn new(input: &[String], cube_wrapping: bool) -> Map {
+        let rows = input.iter().position(|line| line.is_empty()).unwrap_or(input.len());
+        let map = Grid::parse(&input[.. rows], |c| match c {
+            '.' => Some(Object::Floor),
+            '#' => Some(Object::Wall),
+            _ => None,
+        });
+        let pos = map.iter()
+                     .find(|(_, tile)| matches!(tile, Some(Object::Floor)))
+                     .map(|(pos, _)| Point2::from(pos));
+
+        let mut instructions = Vec::new();
+        let mut number = 0;
+        for c in input.last().unwrap().chars() {
+            if c.is_ascii_digit() {
+                number *= 10;
+                number += c.to_digit(10).unwrap() as isize;
+            } else {
+                if number > 0 {
+                    instructions.push(Instruction::Move { amount: number });
+                    number = 0;
+                }
+                match c {
+                    'R' => instructions.push(Instruction::Right),
+                    'L' => instructions.push(Instruction::Left),
+                    _ => unimplemented!(),
+                }
+            }
+        }
+        if number > 0 {
+            instructions.push(Instruction::Move { amount: number });
+        }
+
+        Map { map, instructions, pos: pos.unwrap(), direction: Dir4::Right, cube_wrapping }
+    }
+
+    fn tile(&self, pos: Point2) -> Option<Object> {
+        self.map.get(pos.into()).copied().flatten()
+    }
+
+    fn opposite_position(&self) -> Option<(Point2,Dir4)> {
+        let direction = self.direction.opposite();
+        let mut old_pos = self.pos;
+        let mut old_object = Object::Floor;
+        loop {
+            let check_pos = old_pos.step(direction);
+            match self.tile(check_pos) {
+                None => return match old_object {
+                    Object::Floor => Some((old_pos,self.direction)),
+                    Object::Wall => None,
+                },
+                Some(obj) => old_object = obj,
+            }
+            old_pos = check_pos;
+        }
+    }
+
+    fn next_cube_position(&self) -> Option<(Point2,Dir4)> {
+        let (new_pos, new_direction) = match self.direction {
+            Dir4::Left => {
+                match self.pos.x {
+                    0 => {
+                        if (100..150).contains(&self.pos.y) {
+                            // 100 -> 49, 149 -> 0
+                            (Point2::new(50, 49 - (self.pos.y - 100)), Dir4::Right)
+                        } else if (150..200).contains(&self.pos.y) {
+                            // 150 -> 50, 199 -> 99
+                            (Point2::new(self.pos.y - 100, 0), Dir4::Down)
+                        } else { panic!("no wrapping expected"); }
+                    },
+                    50 => {
+                        if (0..50).contains(&self.pos.y) {
+                            // 49 -> 100, 0 -> 149
+                            (Point2::new(0, 149 - self.pos.y), Dir4::Right)
+                        } else if (50..100).contains(&self.pos.y) {
+                            // 50 -> 0, 99 -> 49
+                            (Point2::new(self.pos.y - 50, 100), Dir4::Down)
+                        } else { panic!("no wrapping expected"); }
+                    },
+                    _ => panic!("no wrapping expected"),
+                }
+            },
+            Dir4::Right => {
+                match self.pos.x {
+                    49 => {
+                        if (150..200).contains(&self.pos.y) {
+                            // 150 -> 50, 199 -> 99
+                            (Point2::new(self.pos.y - 100, 149), Dir4::Up)
+                        } else { panic!("no wrapping expected"); }
+                    },
+                    99 => {
+                        if (50..100).contains(&self.pos.y) {
+                            // 50 -> 100, 99 -> 149
+                            (Point2::new(self.pos.y + 50, 49), Dir4::Up)
+                        } else if (100..150).contains(&self.pos.y) {
+                            // 149 -> 0, 100 -> 49
+                            (Point2::new(149, 49 - (self.pos.y - 100)), Dir4::Left)
+                        } else { panic!("no wrapping expected"); }
+                    },
+                    149 => {
+                        if (0..50).contains(&self.pos.y) {
+                            // 0 -> 149, 49 -> 100
+                            (Point2::new(99, 149 - self.pos.y), Dir4::Left)
+                        } else { panic!("no wrapping expected"); }
+                    },
+                    _ => panic!("no wrapping expected"),
+                }
+            },
+            Dir4::Up => {
+                match self.pos.y {
+                    0 => {
+                        if (50..100).contains(&self.pos.x) {
+                            // 50 -> 150, 99 -> 199
+                            (Point2::new(0, self.pos.x + 100), Dir4::Right)
+                        } else if (100..150).contains(&self.pos.x) {
+                            // 100 -> 0, 149 -> 49
+                            (Point2::new(self.pos.x - 100, 199), Dir4::Up)
+                        } else { panic!("no wrapping expected"); }
+                    },
+                    100 => {
+                        if (0..50).contains(&self.pos.x) {
+                            // 0 -> 50, 49 -> 99
+                            (Point2::new(50, self.pos.x + 50), Dir4::Right)
+                        } else { panic!("no wrapping expected"); }
+                    },
+                    _ => panic!("no wrapping expected"),
+                }
+            },
+            Dir4::Down => {
+                match self.pos.y {
+                    49 => {
+                        if (100..150).contains(&self.pos.x) {
+                            // 100 -> 50, 149 -> 99
+                            (Point2::new(99, self.pos.x - 50), Dir4::Left)
+                        } else { panic!("no wrapping expected"); }
+                    },
+                    149 => {
+                        if (50..100).contains(&self.pos.x) {
+                            // 50 -> 150, 99 -> 199
+                            (Point2::new(49, self.pos.x + 100), Dir4::Left)
+                        } else { panic!("no wrapping expected"); }
+                    },
+                    199 => {
+                        if (0..50).contains(&self.pos.x) {
+                            // 0 -> 100, 49 -> 149
+                            (Point2::new(self.pos.x + 100, 0), Dir4::Down)
+                        } else { panic!("no wrapping expected"); }
+                    },
+                    _ => panic!("no wrapping expected"),
+                }
+            }
+        };
+        if let Some(Object::Wall) = self.tile(new_pos) {
+            None
+        } else {
+            Some((new_pos, new_direction))
+        }
+    }
+
+    fn wrapped_position(&self) -> Option<(Point2,Dir4)> {
+        if self.cube_wrapping {
+            self.next_cube_position()
+        } else {
+            self.opposite_position()
+        }
+    }
+
+    fn step_forward(&self) -> (Point2,Dir4) {
+        let next_pos = self.pos.step(self.direction);
+        match self.tile(next_pos) {
+            None => self.wrapped_position().unwrap_or((self.pos, self.direction)),
+            Some(Object::Wall) => (self.pos, self.direction),
+            Some(Object::Floor) => (next_pos, self.direction),
+        }
+    }
+
+    fn run(&mut self) {
+        for instruction in &self.instructions {
+            match instruction {
+                Instruction::Left => {
+                    self.direction = self.direction.turn_left();
+                },
+                Instruction::Right => {
+                    self.direction = self.direction.turn_right();
+                },
+                Instruction::Move { amount } => {
+                    for _ in 0 .. *amount {
+                        (self.pos, self.direction) = self.step_forward();
+                    }
+                },
+            }
+        }
+    }
+
+    fn password(&self) -> isize {
+        let facing = match self.direction {
+            Dir4::Right => 0,
+            Dir4::Down => 1,
+            Dir4::Left => 2,
+            Dir4::Up => 3,
+        };
+        1000 * (self.pos.y + 1) + 4 * (self.pos.x + 1) + facing
+    }
+}
+
+fn find_password(map: &Map, cube_wrapping: bool) -> isize {
+    let mut map = Map { cube_wrapping, ..map.clone() };
+    map.run();
+    map.password()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "        ...#",
+            "        .#..",
+            "        #...",
+            "        ....",
+            "...#.......#",
+            "........#...",
+            "..#....#....",
+            "..........#.",
+            "        ...#....",
+            "        .....#..",
+            "        .#......",
+            "        ......#.",
+            "",
+            "10R5L5R10L4R5L5",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+
+        let map = Map::new(&input, false);
+        assert_eq!(find_password(&map, false), 6032);
+        //assert_eq!(find_password(&map, true), 5031);
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+use crate::grid::SparseGrid;
+use crate::{lines, Answer, Error, Solution};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Map, Error> {
+        Ok(Map::new(&lines(input)))
+    }
+
+    fn part1(&self, map: &Map) -> Result<Answer, Error> {
+        Ok(ground_tiles(map).into())
+    }
+
+    fn part2(&self, map: &Map) -> Result<Answer, Error> {
+        Ok(no_movement(map).into())
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    elves: SparseGrid<()>,
+    proposed: HashMap<(isize,isize), usize>,
+    rounds: usize,
+}
+
+impl Map {
+    fn new(input: &[String]) -> Map {
+        let elves = SparseGrid::parse(input, |symbol| (symbol == '#').then_some(()));
+        Map { elves, proposed: HashMap::new(), rounds: 0 }
+    }
+
+    fn has_neighbors(&self, pos: (isize, isize), direction: Option<usize>) -> bool {
+        let (x,y) = pos;
+        match direction {
+            Some(0) => self.elves.contains((x-1,y-1)) || self.elves.contains((x,y-1)) || self.elves.contains((x+1,y-1)),
+            Some(1) => self.elves.contains((x-1,y+1)) || self.elves.contains((x,y+1)) || self.elves.contains((x+1,y+1)),
+            Some(2) => self.elves.contains((x-1,y-1)) || self.elves.contains((x-1,y)) || self.elves.contains((x-1,y+1)),
+            Some(3) => self.elves.contains((x+1,y-1)) || self.elves.contains((x+1,y)) || self.elves.contains((x+1,y+1)),
+            Some(_) => unimplemented!(),
+            None => self.has_neighbors(pos, Some(0)) || self.has_neighbors(pos, Some(1)) || self.has_neighbors(pos, Some(2)) || self.has_neighbors(pos, Some(3))
+        }
+    }
+
+    fn next_round(&mut self) {
+        let direction = self.rounds % 4;
+
+        /* first half */
+        for elf in self.elves.positions() {
+            let (x,y) = elf;
+            if !self.has_neighbors(elf, None) {
+                continue;
+            }
+            for d in 0 .. 4 {
+                let d = (direction + d) % 4;
+                if !self.has_neighbors(elf, Some(d)) {
+                    match d {
+                        0 => *self.proposed.entry((x,y-1)).or_insert(0) += 1,
+                        1 => *self.proposed.entry((x,y+1)).or_insert(0) += 1,
+                        2 => *self.proposed.entry((x-1,y)).or_insert(0) += 1,
+                        3 => *self.proposed.entry((x+1,y)).or_insert(0) += 1,
+                        _ => unimplemented!(),
+                    };
+                    break;
+                }
+            }
+        }
+
+        /* second half */
+        let mut new_positions = SparseGrid::new();
+        for elf in self.elves.positions() {
+            let (x,y) = elf;
+            if !self.has_neighbors(elf, None) {
+                new_positions.insert(elf, ());
+                continue;
+            }
+            if self.has_neighbors(elf, Some(0)) && self.has_neighbors(elf, Some(1)) && self.has_neighbors(elf, Some(2)) && self.has_neighbors(elf, Some(3)) {
+                new_positions.insert(elf, ());
+                continue;
+            }
+            for d in 0 .. 4 {
+                let d = (direction + d) % 4;
+                if !self.has_neighbors(elf, Some(d)) {
+                    match d {
+                        0 => if *self.proposed.get(&(x,y-1)).unwrap() == 1 {
+                            new_positions.insert((x,y-1), ())
+                        } else {
+                            new_positions.insert((x,y), ())
+                        },
+                        1 => if *self.proposed.get(&(x,y+1)).unwrap() == 1 {
+                            new_positions.insert((x,y+1), ())
+                        } else {
+                            new_positions.insert((x,y), ())
+                        },
+                        2 => if *self.proposed.get(&(x-1,y)).unwrap() == 1 {
+                            new_positions.insert((x-1,y), ())
+                        } else {
+                            new_positions.insert((x,y), ())
+                        },
+                        3 => if *self.proposed.get(&(x+1,y)).unwrap() == 1 {
+                            new_positions.insert((x+1,y), ())
+                        } else {
+                            new_positions.insert((x,y), ())
+                        },
+                        _ => unimplemented!(),
+                    };
+                    break;
+                }
+            }
+        }
+
+        self.elves = new_positions;
+        self.proposed.clear();
+        self.rounds += 1;
+    }
+
+    fn count_ground_tiles(&self) -> usize {
+        let ((min_x, min_y), (max_x, max_y)) = self.elves.bounds().expect("map should have elves");
+        ((max_x.abs_diff(min_x) + 1) * (max_y.abs_diff(min_y) + 1)) - self.elves.len()
+    }
+}
+
+fn ground_tiles(map: &Map) -> usize {
+    let mut map = map.clone();
+    for _ in 0 .. 10 {
+        map.next_round();
+    }
+    map.count_ground_tiles()
+}
+
+fn no_movement(map: &Map) -> usize {
+    let mut map = map.clone();
+    let mut old_positions = SparseGrid::new();
+    while map.elves != old_positions {
+        old_positions = map.elves.clone();
+        map.next_round();
+    }
+    map.rounds
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "....#..",
+            "..###.#",
+            "#...#.#",
+            ".#...##",
+            "#.###..",
+            "##.#.##",
+            ".#..#..",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+
+        let map = Map::new(&input);
+        assert_eq!(ground_tiles(&map), 110);
+        assert_eq!(no_movement(&map), 20);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::geom::{Dir4, Point2};
+use crate::grid::Grid;
+use crate::search;
+use crate::{lines, Answer, Error, Solution};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Map, Error> {
+        Ok(Map::new(&lines(input)))
+    }
+
+    fn part1(&self, map: &Map) -> Result<Answer, Error> {
+        Ok(travel_time(map).into())
+    }
+
+    fn part2(&self, map: &Map) -> Result<Answer, Error> {
+        Ok(travel_time_3(map).into())
+    }
+}
+
+#[derive(Clone,Copy)]
+enum Object {
+    Wall,
+    Blizzard { direction: Dir4 },
+}
+
+impl Object {
+    fn new(input: char) -> Object {
+        match input {
+            '#' => Object::Wall,
+            c => Object::Blizzard { direction: Dir4::from_char(c).expect("blizzard should have a direction") },
+        }
+    }
+}
+
+pub struct Map {
+    free: Vec<Grid<bool>>,  // free positions for every minute until the blizzards repeat
+    start: Point2,
+    end: Point2,
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+impl Map {
+    fn new(input: &[String]) -> Map {
+        let mut map = Grid::parse(input, |c| match c {
+            '.' => Vec::new(),
+            c => vec![Object::new(c)],
+        });
+        let dimensions = (map.width() as isize - 1, map.height() as isize - 1);
+        let opening = |y| map.iter()
+                             .find(|&((_, row), objects)| row == y && objects.is_empty())
+                             .map(|(pos, _)| Point2::from(pos))
+                             .expect("valley should have an opening");
+        let (start, end) = (opening(0), opening(map.height() - 1));
+
+        /* the blizzards are back in their initial positions after this many minutes */
+        let (inner_width, inner_height) = (map.width() - 2, map.height() - 2);
+        let period = inner_width * inner_height / gcd(inner_width, inner_height);
+        let mut free = Vec::new();
+        for _ in 0 .. period {
+            free.push(map.map(Vec::is_empty));
+            map = next_map(&map, dimensions);
+        }
+        Map { free, start, end }
+    }
+
+    fn travel_time(&self, from: Point2, to: Point2, departure: usize) -> usize {
+        let period = self.free.len();
+        let neighbors = |&(pos, time): &(Point2, usize)| {
+            let next_time = (time + 1) % period;
+            /* waiting is allowed as well */
+            pos.neighbors4().into_iter()
+                            .chain([pos])
+                            .filter(move |&pos| self.free[next_time].get(pos.into()) == Some(&true))
+                            .map(move |pos| (pos, next_time))
+        };
+        let path = search::bfs([(from, departure % period)], neighbors, |&(pos, _)| pos == to)
+                          .expect("valley should be passable");
+        departure + path.cost
+    }
+}
+
+fn next_map(map: &Grid<Vec<Object>>, dimensions: (isize, isize)) -> Grid<Vec<Object>> {
+    let mut new_map = Grid::new(map.width(), map.height(), Vec::new());
+    for (pos, objects) in map.iter() {
+        let pos = Point2::from(pos);
+        for object in objects {
+            let new_pos = match object {
+                Object::Wall => pos,
+                Object::Blizzard { direction } => {
+                    /* blizzards reaching a wall come back on the opposite side */
+                    let mut new_pos = pos.step(*direction);
+                    if new_pos.x == 0 { new_pos.x = dimensions.0 - 1; }
+                    if new_pos.x == dimensions.0 { new_pos.x = 1; }
+                    if new_pos.y == 0 { new_pos.y = dimensions.1 - 1; }
+                    if new_pos.y == dimensions.1 { new_pos.y = 1; }
+                    new_pos
+                },
+            };
+            new_map.get_mut(new_pos.into()).expect("blizzards should stay inside the valley").push(*object);
+        }
+    }
+    new_map
+}
+
+fn travel_time(map: &Map) -> usize {
+    map.travel_time(map.start, map.end, 0)
+}
+
+fn travel_time_3(map: &Map) -> usize {
+    let mut time = map.travel_time(map.start, map.end, 0);
+    time = map.travel_time(map.end, map.start, time);
+    time = map.travel_time(map.start, map.end, time);
+
+    time
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "#.######",
+            "#>>.<^<#",
+            "#.<..<<#",
+            "#>v.><>#",
+            "#<^v^^>#",
+            "######.#",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+
+        let map = Map::new(&input);
+        assert_eq!(travel_time(&map), 18);
+        assert_eq!(travel_time_3(&map), 54);
+    }
+}
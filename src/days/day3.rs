@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+use std::fmt;
+use crate::{lines, Answer, Error, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, Error> {
+        Ok(lines(input))
+    }
+
+    fn part1(&self, input: &Vec<String>) -> Result<Answer, Error> {
+        Ok(sum_priorities(&Priorities::letters(), input).map_err(Error::invalid)?.into())
+    }
+
+    fn part2(&self, input: &Vec<String>) -> Result<Answer, Error> {
+        Ok(sum_badge_priorities(&Priorities::letters(), input, 3).map_err(Error::invalid)?.into())
+    }
+}
+
+/// Problems with the rucksack list; indices count from 0.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RucksackError {
+    OddLength { rucksack: usize },
+    UnknownItem { rucksack: usize, item: char },
+    /// The compartments of a rucksack don't share exactly one item.
+    SharedItems { rucksack: usize, count: u32 },
+    /// The rucksacks of a group don't share exactly one item.
+    CommonItems { group: usize, count: u32 },
+    IncompleteGroup { group: usize, size: usize },
+    EmptyGroups,
+}
+
+impl fmt::Display for RucksackError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RucksackError::OddLength { rucksack } => write!(f, "rucksack {}: odd number of items", rucksack),
+            RucksackError::UnknownItem { rucksack, item } => write!(f, "rucksack {}: unknown item {:?}", rucksack, item),
+            RucksackError::SharedItems { rucksack, count } => write!(f, "rucksack {}: {} items in both compartments instead of one", rucksack, count),
+            RucksackError::CommonItems { group, count } => write!(f, "group {}: {} common items instead of one", group, count),
+            RucksackError::IncompleteGroup { group, size } => write!(f, "group {}: only {} rucksacks", group, size),
+            RucksackError::EmptyGroups => write!(f, "groups need at least one rucksack"),
+        }
+    }
+}
+
+impl std::error::Error for RucksackError {}
+
+/// Items that may appear in rucksacks and their priorities.
+/// Every item gets a bit in a `u128` set, so a table holds up to 128 items.
+#[derive(Debug, Clone)]
+pub struct Priorities {
+    items: Vec<char>,
+    weights: Vec<u32>,
+    /* bit of each item, looked up directly for ASCII */
+    ascii: [Option<u8>; 128],
+    other: HashMap<char, u8>,
+}
+
+impl Priorities {
+    pub const MAX_ITEMS: usize = 128;
+
+    fn new() -> Priorities {
+        Priorities { items: Vec::new(), weights: Vec::new(), ascii: [None; 128], other: HashMap::new() }
+    }
+
+    /// The puzzle's table: `a-z` are worth 1 to 26, `A-Z` 27 to 52.
+    pub fn letters() -> Priorities {
+        Priorities::parse("a-z 1\nA-Z 27").unwrap()
+    }
+
+    /// Reads one definition per line, either `ITEM WEIGHT` or `FIRST-LAST WEIGHT`;
+    /// a range gives its items consecutive weights starting at `WEIGHT`.
+    pub fn parse(input: &str) -> Result<Priorities, String> {
+        let mut priorities = Priorities::new();
+        for (idx, line) in input.lines().enumerate() {
+            let words = line.split_whitespace().collect::<Vec<_>>();
+            let (items, weight) = match words[..] {
+                [] => continue,
+                [items, weight] => (items.chars().collect::<Vec<_>>(), weight),
+                _ => return Err(format!("line {}: invalid definition: {:?}", idx + 1, line)),
+            };
+            let weight = weight.parse::<u32>().map_err(|e| format!("line {}: {}", idx + 1, e))?;
+            let (first, last) = match items[..] {
+                [item] => (item, item),
+                [first, '-', last] if first <= last => (first, last),
+                _ => return Err(format!("line {}: invalid items: {:?}", idx + 1, line)),
+            };
+            for (offset, item) in (first ..= last).enumerate() {
+                priorities.insert(item, weight + offset as u32).map_err(|e| format!("line {}: {}", idx + 1, e))?;
+            }
+        }
+        Ok(priorities)
+    }
+
+    fn insert(&mut self, item: char, weight: u32) -> Result<(), String> {
+        if self.bit(item).is_some() {
+            return Err(format!("item {:?} defined twice", item));
+        }
+        if self.items.len() == Priorities::MAX_ITEMS {
+            return Err(format!("more than {} items", Priorities::MAX_ITEMS));
+        }
+        let bit = self.items.len() as u8;
+        match self.ascii.get_mut(item as usize) {
+            Some(slot) => *slot = Some(bit),
+            None => { self.other.insert(item, bit); },
+        }
+        self.items.push(item);
+        self.weights.push(weight);
+        Ok(())
+    }
+
+    fn bit(&self, item: char) -> Option<u8> {
+        match self.ascii.get(item as usize) {
+            Some(&bit) => bit,
+            None => self.other.get(&item).copied(),
+        }
+    }
+
+    /// Priority of `item`, `None` if the table doesn't know it.
+    pub fn weight(&self, item: char) -> Option<u32> {
+        self.bit(item).map(|bit| self.weights[bit as usize])
+    }
+}
+
+impl Default for Priorities {
+    fn default() -> Priorities {
+        Priorities::letters()
+    }
+}
+
+/* set of items with one bit per item of the table */
+fn item_mask(priorities: &Priorities, items: &str, rucksack: usize) -> Result<u128, RucksackError> {
+    items.chars().try_fold(0, |mask, item| match priorities.bit(item) {
+        Some(bit) => Ok(mask | 1 << bit),
+        None => Err(RucksackError::UnknownItem { rucksack, item }),
+    })
+}
+
+/* the only item of the set, or the number of items it has instead */
+fn single_item(priorities: &Priorities, mask: u128) -> Result<char, u32> {
+    match mask.count_ones() {
+        1 => Ok(priorities.items[mask.trailing_zeros() as usize]),
+        count => Err(count),
+    }
+}
+
+/// The item in both compartments of every rucksack.
+pub fn shared_items(priorities: &Priorities, input: &[String]) -> Result<Vec<char>, RucksackError> {
+    input.iter()
+         .enumerate()
+         .map(|(idx, line)| {
+             let count = line.chars().count();
+             if count % 2 != 0 {
+                 return Err(RucksackError::OddLength { rucksack: idx });
+             }
+             let middle = line.char_indices().nth(count / 2).map_or(0, |(pos, _)| pos);
+             let (first, second) = line.split_at(middle);
+             let shared = item_mask(priorities, first, idx)? & item_mask(priorities, second, idx)?;
+             single_item(priorities, shared).map_err(|count| RucksackError::SharedItems { rucksack: idx, count })
+         })
+         .collect()
+}
+
+/// The item carried by all rucksacks of every group of `group_size` rucksacks.
+pub fn badges(priorities: &Priorities, input: &[String], group_size: usize) -> Result<Vec<char>, RucksackError> {
+    if group_size == 0 {
+        return Err(RucksackError::EmptyGroups);
+    }
+    input.chunks(group_size)
+         .enumerate()
+         .map(|(group, rucksacks)| {
+             if rucksacks.len() < group_size {
+                 return Err(RucksackError::IncompleteGroup { group, size: rucksacks.len() });
+             }
+             let mut common = u128::MAX;
+             for (idx, rucksack) in rucksacks.iter().enumerate() {
+                 common &= item_mask(priorities, rucksack, group * group_size + idx)?;
+             }
+             single_item(priorities, common).map_err(|count| RucksackError::CommonItems { group, count })
+         })
+         .collect()
+}
+
+pub fn sum_priorities(priorities: &Priorities, input: &[String]) -> Result<u32, RucksackError> {
+    Ok(shared_items(priorities, input)?.into_iter().filter_map(|item| priorities.weight(item)).sum())
+}
+
+pub fn sum_badge_priorities(priorities: &Priorities, input: &[String], group_size: usize) -> Result<u32, RucksackError> {
+    Ok(badges(priorities, input, group_size)?.into_iter().filter_map(|item| priorities.weight(item)).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "vJrwpWtwJgWrhcsFMMfFFhFp".to_string(),
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL".to_string(),
+            "PmmdzqPrVvPwwTWBwg".to_string(),
+            "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn".to_string(),
+            "ttgJtRGJQctTZtZT".to_string(),
+            "CrZsJsPPZsGzwwsLwLmpwMDw".to_string(),
+        ];
+        let letters = Priorities::letters();
+
+        assert_eq!(sum_priorities(&letters, &input), Ok(157));
+        assert_eq!(sum_badge_priorities(&letters, &input, 3), Ok(70));
+        assert_eq!(shared_items(&letters, &input), Ok(vec!['p', 'L', 'P', 'v', 't', 's']));
+        assert_eq!(badges(&letters, &input, 3), Ok(vec!['r', 'Z']));
+        assert_eq!(badges(&letters, &input, 2), Err(RucksackError::CommonItems { group: 0, count: 5 }));
+        assert_eq!(badges(&letters, &input[.. 5], 3), Err(RucksackError::IncompleteGroup { group: 1, size: 2 }));
+        assert_eq!(badges(&letters, &input, 1), Err(RucksackError::CommonItems { group: 0, count: 14 }));
+        assert_eq!(badges(&letters, &input, 0), Err(RucksackError::EmptyGroups));
+
+        let broken = [
+            "abca".to_string(),
+            "abc".to_string(),
+            "ab-b".to_string(),
+        ];
+        assert_eq!(shared_items(&letters, &broken[.. 1]), Ok(vec!['a']));
+        assert_eq!(shared_items(&letters, &broken), Err(RucksackError::OddLength { rucksack: 1 }));
+        assert_eq!(shared_items(&letters, &broken[2 ..]), Err(RucksackError::UnknownItem { rucksack: 0, item: '-' }));
+        assert_eq!(shared_items(&letters, &["abab".to_string()]), Err(RucksackError::SharedItems { rucksack: 0, count: 2 }));
+        assert_eq!(RucksackError::SharedItems { rucksack: 3, count: 0 }.to_string(), "rucksack 3: 0 items in both compartments instead of one");
+        assert_eq!(RucksackError::CommonItems { group: 4, count: 0 }.to_string(), "group 4: 0 common items instead of one");
+
+        /* digits, punctuation and other symbols with their own weights */
+        let priorities = Priorities::parse("a-z 1\n\n0-9 100\n! 500\né 7\n☃ 1000").unwrap();
+        let extended = [
+            "a!3☃b!4é".to_string(),
+            "☃☃x1ty☃z".to_string(),
+            "é☃qx!é".to_string(),
+        ];
+        assert_eq!(priorities.weight('3'), Some(103));
+        assert_eq!(priorities.weight('A'), None);
+        assert_eq!(shared_items(&priorities, &extended), Ok(vec!['!', '☃', 'é']));
+        assert_eq!(sum_priorities(&priorities, &extended), Ok(1507));
+        assert_eq!(badges(&priorities, &extended, 3), Ok(vec!['☃']));
+        assert_eq!(sum_badge_priorities(&priorities, &extended, 3), Ok(1000));
+        assert_eq!(shared_items(&letters, &extended), Err(RucksackError::UnknownItem { rucksack: 0, item: '!' }));
+
+        assert_eq!(Priorities::parse("a 1\nb-a 2").unwrap_err(), "line 2: invalid items: \"b-a 2\"");
+        assert_eq!(Priorities::parse("a-c 1\nb 2").unwrap_err(), "line 2: item 'b' defined twice");
+        assert!(Priorities::parse("a x").is_err());
+        assert!(Priorities::parse("\u{100}-\u{200} 1").is_err());
+    }
+}
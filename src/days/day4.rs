@@ -0,0 +1,203 @@
+use crate::interval::Interval;
+use crate::{lines, Answer, Error, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<(Interval, Interval)>;
+
+    fn parse(&self, input: &str) -> Result<Vec<(Interval, Interval)>, Error> {
+        Ok(parse_pairs(&lines(input)))
+    }
+
+    fn part1(&self, pairs: &Vec<(Interval, Interval)>) -> Result<Answer, Error> {
+        Ok(overlaps(pairs, true).into())
+    }
+
+    fn part2(&self, pairs: &Vec<(Interval, Interval)>) -> Result<Answer, Error> {
+        Ok(overlaps(pairs, false).into())
+    }
+}
+
+fn parse_interval(input: &str) -> Interval {
+    let (start, end) = input.split_once('-').unwrap();
+    let start = start.parse::<i64>().unwrap();
+    let end = end.parse::<i64>().unwrap();
+    Interval::closed(start, end)
+}
+
+fn parse_pairs(input: &[String]) -> Vec<(Interval, Interval)> {
+    input.iter()
+         .map(|line| line.split_once(',').unwrap())
+         .map(|(range1, range2)| (parse_interval(range1), parse_interval(range2)))
+         .collect()
+}
+
+/// Number of sections both elves of each pair have to clean.
+pub fn overlap_sizes(pairs: &[(Interval, Interval)]) -> Vec<u64> {
+    pairs.iter()
+         .map(|(range1, range2)| range1.intersection(range2).len())
+         .collect()
+}
+
+fn overlaps(pairs: &[(Interval, Interval)], fully_contained: bool) -> usize {
+    pairs.iter()
+         .zip(overlap_sizes(pairs))
+         .filter(|&((range1, range2), size)| {
+             if fully_contained {
+                 /* the overlap is all of the smaller range */
+                 size == range1.len().min(range2.len())
+             } else {
+                 size > 0
+             }
+         })
+         .count()
+}
+
+/// All assignments; the elves of pair `n` are at `2 * n` and `2 * n + 1`.
+pub fn assignments(pairs: &[(Interval, Interval)]) -> Vec<Interval> {
+    pairs.iter()
+         .flat_map(|&(range1, range2)| [range1, range2])
+         .collect()
+}
+
+/// Number of elves cleaning each section, from the first to the last assigned section.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    /// Adjacent intervals of sections with the same number of elves, in ascending order.
+    pub segments: Vec<(Interval, usize)>,
+}
+
+impl Coverage {
+    pub fn new(assignments: &[Interval]) -> Coverage {
+        /* sweep over the boundaries, +1 where an assignment starts and -1 where it ends */
+        let mut events = assignments.iter()
+                                    .filter(|a| !a.is_empty())
+                                    .flat_map(|a| [(a.start, 1), (a.end, -1)])
+                                    .collect::<Vec<(i64, isize)>>();
+        events.sort_unstable();
+
+        let mut segments: Vec<(Interval, usize)> = Vec::new();
+        let mut elves = 0;
+        let mut previous = None;
+        for (position, change) in events {
+            match previous {
+                Some(start) if start < position => match segments.last_mut() {
+                    Some((last, count)) if *count == elves as usize => last.end = position,
+                    _ => segments.push((Interval::new(start, position), elves as usize)),
+                },
+                _ => {},
+            }
+            elves += change;
+            previous = Some(position);
+        }
+        Coverage { segments }
+    }
+
+    /// Number of sections cleaned by a number of elves matching `elves`.
+    pub fn sections(&self, elves: impl Fn(usize) -> bool) -> u64 {
+        self.segments.iter()
+                     .filter(|&&(_, count)| elves(count))
+                     .map(|(interval, _)| interval.len())
+                     .sum()
+    }
+
+    /// The highest number of elves cleaning the same section, and the first
+    /// sections where that happens.
+    pub fn max_concurrency(&self) -> Option<(usize, Interval)> {
+        self.segments.iter()
+                     .rev()
+                     .max_by_key(|&&(_, count)| count)
+                     .map(|&(interval, count)| (count, interval))
+    }
+}
+
+/// Smallest set of assignments covering every section that any elf cleans,
+/// as indices into `assignments`; all others are redundant.
+pub fn minimal_cover(assignments: &[Interval]) -> Vec<usize> {
+    let mut order = (0 .. assignments.len()).filter(|&idx| !assignments[idx].is_empty()).collect::<Vec<_>>();
+    order.sort_by_key(|&idx| assignments[idx].start);
+
+    /* greedily take the assignment reaching furthest among those starting within the covered part */
+    let mut cover = Vec::new();
+    let mut next = 0;
+    while next < order.len() {
+        let mut covered = assignments[order[next]].start;
+        let mut best = None;
+        loop {
+            while next < order.len() && assignments[order[next]].start <= covered {
+                let idx = order[next];
+                if best.is_none_or(|b: usize| assignments[idx].end > assignments[b].end) {
+                    best = Some(idx);
+                }
+                next += 1;
+            }
+            match best.take() {
+                Some(idx) if assignments[idx].end > covered => {
+                    covered = assignments[idx].end;
+                    cover.push(idx);
+                },
+                _ => break,
+            }
+        }
+    }
+    cover.sort_unstable();
+    cover
+}
+
+/// Assignments that can be dropped together without leaving any section uncleaned.
+pub fn redundant_assignments(assignments: &[Interval]) -> Vec<usize> {
+    let cover = minimal_cover(assignments);
+    (0 .. assignments.len()).filter(|idx| cover.binary_search(idx).is_err())
+                            .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "2-4,6-8".to_string(),
+            "2-3,4-5".to_string(),
+            "5-7,7-9".to_string(),
+            "2-8,3-7".to_string(),
+            "6-6,4-6".to_string(),
+            "2-6,4-8".to_string(),
+        ];
+
+        let pairs = parse_pairs(&input);
+        assert_eq!(overlaps(&pairs, true), 2);
+        assert_eq!(overlaps(&pairs, false), 4);
+        assert_eq!(overlap_sizes(&pairs), [0, 0, 1, 5, 1, 3]);
+
+        let assignments = assignments(&pairs);
+        let coverage = Coverage::new(&assignments);
+        assert_eq!(coverage.segments, [
+            (Interval::new(2, 3), 4),
+            (Interval::new(3, 4), 5),
+            (Interval::new(4, 6), 7),
+            (Interval::new(6, 7), 8),
+            (Interval::new(7, 8), 6),
+            (Interval::new(8, 9), 4),
+            (Interval::new(9, 10), 1),
+        ]);
+        assert_eq!(coverage.sections(|elves| elves == 0), 0);
+        assert_eq!(coverage.sections(|elves| elves == 1), 1);
+        assert_eq!(coverage.sections(|elves| elves > 1), 7);
+        assert_eq!(coverage.max_concurrency(), Some((8, Interval::new(6, 7))));
+        assert_eq!(minimal_cover(&assignments), [5, 6]);
+        assert_eq!(redundant_assignments(&assignments).len(), 10);
+
+        /* a gap, touching assignments and a chain that needs both ends */
+        let assignments = ["1-2", "5-5", "3-3", "7-9", "8-12", "10-13", "8-8"].map(parse_interval);
+        let coverage = Coverage::new(&assignments);
+        assert_eq!(coverage.sections(|elves| elves == 0), 2);
+        assert_eq!(coverage.sections(|elves| elves == 1), 6);
+        assert_eq!(coverage.max_concurrency(), Some((3, Interval::new(8, 9))));
+        assert_eq!(minimal_cover(&assignments), [0, 1, 2, 3, 5]);
+        assert_eq!(redundant_assignments(&assignments), [4, 6]);
+        assert_eq!(Coverage::new(&[]).max_concurrency(), None);
+    }
+}
@@ -0,0 +1,595 @@
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::fmt;
+use regex::Regex;
+use crate::image::Image;
+use crate::search;
+use crate::{lines, Answer, Error, Solution};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = CrateMover;
+
+    fn parse(&self, input: &str) -> Result<CrateMover, Error> {
+        Ok(CrateMover::new(&lines(input)))
+    }
+
+    fn part1(&self, cratemover: &CrateMover) -> Result<Answer, Error> {
+        Ok(find_top_crates(cratemover).map_err(Error::invalid)?.into())
+    }
+
+    fn part2(&self, cratemover: &CrateMover) -> Result<Answer, Error> {
+        Ok(find_top_crates_9001(cratemover).map_err(Error::invalid)?.into())
+    }
+}
+
+#[derive(Clone)]
+pub struct CrateMover {
+    instructions: Vec<Instruction>,
+    /* input line of every instruction, 1-based */
+    lines: Vec<usize>,
+    stacks: Vec<VecDeque<char>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    amount: usize,
+    from: usize,
+    to: usize,
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.amount, self.from.wrapping_add(1), self.to.wrapping_add(1))
+    }
+}
+
+/// Why an instruction can't be carried out; stacks are numbered from 1 as in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    NoSuchStack { stack: usize, stacks: usize },
+    NotEnoughCrates { stack: usize, size: usize },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveError {
+    /// Position in the instruction list, from 0.
+    pub index: usize,
+    /// Input line of the instruction, from 1.
+    pub line: usize,
+    pub instruction: Instruction,
+    pub problem: Problem,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {} (instruction {}, {:?}): ", self.line, self.index, self.instruction.to_string())?;
+        match self.problem {
+            Problem::NoSuchStack { stack, stacks } => write!(f, "there is no stack {}, only {}", stack, stacks),
+            Problem::NotEnoughCrates { stack, size } => write!(f, "stack {} only holds {} crates", stack, size),
+        }
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+impl CrateMover {
+    pub fn new(input: &[String]) -> CrateMover {
+        let re_instruction = Regex::new("move ([0-9]+) from ([0-9]+) to ([0-9]+)").unwrap();
+
+        let mut instructions = Vec::new();
+        let mut lines = Vec::new();
+        let mut stacks = Vec::new();
+        for (idx, line) in input.iter().enumerate() {
+            if let Some(cap) = re_instruction.captures(line) {
+                /* stack 0 wraps around to an index that is always out of range */
+                instructions.push(Instruction {
+                    amount: cap[1].parse::<usize>().unwrap(),
+                    from: cap[2].parse::<usize>().unwrap().wrapping_sub(1),
+                    to: cap[3].parse::<usize>().unwrap().wrapping_sub(1),
+                });
+                lines.push(idx + 1);
+            }
+
+            if line.find('[').is_some() {
+                let chars = line.chars().collect::<Vec<_>>();
+                for (index, chunk) in chars.chunks(4).enumerate() {
+                    // chunk: "[x] "
+                    let name = chunk[1];
+                    if name == ' ' {
+                        // empty position
+                        continue;
+                    }
+                    if index >= stacks.len() {
+                        stacks.resize(index + 1, VecDeque::new());
+                    }
+                    stacks[index].push_front(name);
+                }
+            } else if !line.trim().is_empty() && line.chars().all(|c| c.is_ascii_digit() || c == ' ') {
+                /* label row, which also counts stacks without crates */
+                let labels = line.split_whitespace().count();
+                if labels > stacks.len() {
+                    stacks.resize(labels, VecDeque::new());
+                }
+            }
+        }
+
+        CrateMover { stacks, instructions, lines }
+    }
+
+    /// Initial stacks, bottom crate first.
+    pub fn stacks(&self) -> &[VecDeque<char>] {
+        &self.stacks
+    }
+
+    /// Stack states while the instructions are carried out, starting with the
+    /// initial stacks and followed by the state after every step.
+    /// Panics on invalid instructions, see `validate`.
+    pub fn steps(&self, model: Model, step_size: StepSize) -> Steps<'_> {
+        Steps {
+            instructions: &self.instructions,
+            stacks: self.stacks.clone(),
+            model,
+            step_size,
+            next: 0,
+            moved: 0,
+            lifted: VecDeque::new(),
+            started: false,
+        }
+    }
+
+    fn check(&self, sizes: &[usize], index: usize) -> Result<(), MoveError> {
+        let instruction = &self.instructions[index];
+        let error = |problem| MoveError { index, line: self.lines[index], instruction: instruction.clone(), problem };
+        for stack in [instruction.from, instruction.to] {
+            if stack >= sizes.len() {
+                return Err(error(Problem::NoSuchStack { stack: stack.wrapping_add(1), stacks: sizes.len() }));
+            }
+        }
+        if sizes[instruction.from] < instruction.amount {
+            return Err(error(Problem::NotEnoughCrates { stack: instruction.from + 1, size: sizes[instruction.from] }));
+        }
+        Ok(())
+    }
+
+    /// Dry run: checks that every instruction can be carried out, without moving any crates.
+    /// Both models move the same number of crates, so the result holds for either.
+    pub fn validate(&self) -> Result<(), MoveError> {
+        let mut sizes = self.stacks.iter().map(|stack| stack.len()).collect::<Vec<_>>();
+        for (index, instruction) in self.instructions.iter().enumerate() {
+            self.check(&sizes, index)?;
+            sizes[instruction.from] -= instruction.amount;
+            sizes[instruction.to] += instruction.amount;
+        }
+        Ok(())
+    }
+
+    /// Carries out all instructions and returns the final stacks,
+    /// or stops at the first instruction that can't be carried out.
+    pub fn execute(&self, model: Model) -> Result<Vec<VecDeque<char>>, MoveError> {
+        let mut stacks = self.stacks.clone();
+        for (index, instruction) in self.instructions.iter().enumerate() {
+            self.check(&stacks.iter().map(|stack| stack.len()).collect::<Vec<_>>(), index)?;
+            move_crates(&mut stacks, instruction, model);
+        }
+        Ok(stacks)
+    }
+}
+
+/// Crane models, differing in how many crates they lift at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Model {
+    /// Moves one crate at a time, which reverses the moved crates.
+    CrateMover9000,
+    /// Moves all crates of an instruction at once, keeping their order.
+    CrateMover9001,
+}
+
+/// How much happens between two states of `Steps`. With `Crate`, the crates
+/// the 9001 lifted for an instruction are on no stack until they are set down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepSize {
+    Crate,
+    Instruction,
+}
+
+pub struct Steps<'a> {
+    instructions: &'a [Instruction],
+    stacks: Vec<VecDeque<char>>,
+    model: Model,
+    step_size: StepSize,
+    next: usize,
+    /* crates of the next instruction that were already moved */
+    moved: usize,
+    /* crates the 9001 holds while setting them down one by one */
+    lifted: VecDeque<char>,
+    started: bool,
+}
+
+impl Iterator for Steps<'_> {
+    type Item = Vec<VecDeque<char>>;
+
+    fn next(&mut self) -> Option<Vec<VecDeque<char>>> {
+        if !self.started {
+            self.started = true;
+            return Some(self.stacks.clone());
+        }
+        match self.step_size {
+            StepSize::Crate => {
+                let instruction = loop {
+                    let instruction = self.instructions.get(self.next)?;
+                    if self.moved < instruction.amount {
+                        break instruction;
+                    }
+                    self.next += 1;
+                    self.moved = 0;
+                };
+                let name = match self.model {
+                    Model::CrateMover9000 => self.stacks[instruction.from].pop_back(),
+                    Model::CrateMover9001 => {
+                        if self.moved == 0 {
+                            let from = &mut self.stacks[instruction.from];
+                            self.lifted = from.drain(from.len() - instruction.amount ..).collect();
+                        }
+                        self.lifted.pop_front()
+                    },
+                };
+                self.stacks[instruction.to].push_back(name.expect("Stack has no crate"));
+                self.moved += 1;
+            },
+            StepSize::Instruction => {
+                let instruction = self.instructions.get(self.next)?;
+                move_crates(&mut self.stacks, instruction, self.model);
+                self.next += 1;
+            },
+        }
+        Some(self.stacks.clone())
+    }
+}
+
+/* carries out a whole instruction; the 9001 lifts all crates before setting them
+   down, which also keeps a stack unchanged when moving crates onto itself */
+fn move_crates(stacks: &mut [VecDeque<char>], instruction: &Instruction, model: Model) {
+    match model {
+        Model::CrateMover9000 => {
+            for _ in 0 .. instruction.amount {
+                let name = stacks[instruction.from].pop_back().expect("Stack has no crate");
+                stacks[instruction.to].push_back(name);
+            }
+        },
+        Model::CrateMover9001 => {
+            let from = &mut stacks[instruction.from];
+            let lifted = from.drain(from.len() - instruction.amount ..).collect::<Vec<_>>();
+            stacks[instruction.to].extend(lifted);
+        },
+    }
+}
+
+fn top_crates(cratemover: &CrateMover, model: Model) -> Result<String, MoveError> {
+    let stacks = cratemover.execute(model)?;
+    /* a stack without crates has a blank on top */
+    Ok(stacks.iter()
+             .map(|stack| stack.back().copied().unwrap_or(' '))
+             .collect())
+}
+
+fn find_top_crates(cratemover: &CrateMover) -> Result<String, MoveError> {
+    top_crates(cratemover, Model::CrateMover9000)
+}
+
+fn find_top_crates_9001(cratemover: &CrateMover) -> Result<String, MoveError> {
+    top_crates(cratemover, Model::CrateMover9001)
+}
+
+/// Draws the stacks like the puzzle input, ending with the row of stack numbers.
+/// Parsing the drawing with `CrateMover::new` gives back the same stacks.
+pub fn drawing(stacks: &[VecDeque<char>]) -> String {
+    let height = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+    let mut rows = Vec::new();
+    for level in (0 .. height).rev() {
+        let row = stacks.iter()
+                        .map(|stack| stack.get(level).map_or("   ".to_string(), |name| format!("[{}]", name)))
+                        .collect::<Vec<_>>();
+        rows.push(row.join(" "));
+    }
+    let labels = (1 ..= stacks.len()).map(|n| format!("{:^3}", n)).collect::<Vec<_>>();
+    rows.push(labels.join(" "));
+    rows.iter().map(|row| format!("{}\n", row)).collect()
+}
+
+impl fmt::Display for CrateMover {
+    /// The drawing of the initial stacks and the instructions, in the input format.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", drawing(&self.stacks))?;
+        writeln!(f)?;
+        for instruction in &self.instructions {
+            writeln!(f, "{}", instruction)?;
+        }
+        Ok(())
+    }
+}
+
+/// Why `plan` has no instructions.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PlanError {
+    /// The target holds other crates or more stacks.
+    Mismatch,
+    /// The search gave up after `MAX_PLAN_STATES` arrangements, and fewer than 3 stacks leave no room to rebuild the target.
+    TooLarge { crates: usize },
+    /// No instructions lead to the target.
+    Unreachable,
+}
+
+impl fmt::Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PlanError::Mismatch => write!(f, "arrangement has other crates or more stacks"),
+            PlanError::TooLarge { crates } => write!(f, "no plan among the first {} arrangements of {} crates, and fewer than 3 stacks can't be rebuilt", MAX_PLAN_STATES, crates),
+            PlanError::Unreachable => write!(f, "arrangement can't be reached"),
+        }
+    }
+}
+
+impl std::error::Error for PlanError {}
+
+/// Most arrangements `plan` looks at in search of the shortest plan, about a second's worth.
+pub const MAX_PLAN_STATES: usize = 100000;
+
+/* moves the crates above `height` of stack `from` to stack `to` */
+fn lift(stacks: &mut [VecDeque<char>], instructions: &mut Vec<Instruction>, model: Model, from: usize, height: usize, to: usize) {
+    let amount = stacks[from].len() - height;
+    if amount > 0 {
+        let instruction = Instruction { amount, from, to };
+        move_crates(stacks, &instruction, model);
+        instructions.push(instruction);
+    }
+}
+
+/* rebuilds the target bottom up, one stack after the other: the crates on the place of the next crate
+   and on the crate itself go to another stack for later, which needs at least 3 stacks */
+fn rebuild(stacks: &[VecDeque<char>], target: &[VecDeque<char>], model: Model) -> Option<Vec<Instruction>> {
+    if stacks.len() < 3 {
+        return None;
+    }
+    let mut stacks = stacks.to_vec();
+    let mut instructions = Vec::new();
+    /* number of crates at the bottom of every stack that are in their final place */
+    let mut done = vec![0; stacks.len()];
+    /* the last stacks are rebuilt last, so they take the crates in the way */
+    let count = stacks.len();
+    let spare = |excluded: &[usize]| (0 .. count).rev().find(|idx| !excluded.contains(idx)).unwrap();
+
+    for (to, goal) in target.iter().enumerate() {
+        for &name in goal {
+            if stacks[to].get(done[to]) != Some(&name) {
+                lift(&mut stacks, &mut instructions, model, to, done[to], spare(&[to]));
+                /* the crate with the fewest crates on top of it */
+                let (from, height) = (0 .. stacks.len()).filter(|&idx| idx != to)
+                                                        .flat_map(|idx| (done[idx] .. stacks[idx].len()).map(move |height| (idx, height)))
+                                                        .filter(|&(idx, height)| stacks[idx][height] == name)
+                                                        .min_by_key(|&(idx, height)| stacks[idx].len() - height)
+                                                        .expect("target should hold the same crates");
+                lift(&mut stacks, &mut instructions, model, from, height + 1, spare(&[to, from]));
+                lift(&mut stacks, &mut instructions, model, from, height, to);
+            }
+            done[to] += 1;
+        }
+    }
+    Some(instructions)
+}
+
+/// Instructions that rearrange `stacks` into `target` with the given model. A search finds the
+/// shortest plan, unless it takes more than `MAX_PLAN_STATES` arrangements: larger warehouses
+/// get a longer plan that rebuilds the target stack by stack.
+pub fn plan(stacks: &[VecDeque<char>], target: &[VecDeque<char>], model: Model) -> Result<Vec<Instruction>, PlanError> {
+    if target.len() > stacks.len() {
+        return Err(PlanError::Mismatch);
+    }
+    let mut target = target.to_vec();
+    target.resize(stacks.len(), VecDeque::new());
+    let mut crates = stacks.iter().flatten().collect::<Vec<_>>();
+    let mut target_crates = target.iter().flatten().collect::<Vec<_>>();
+    crates.sort_unstable();
+    target_crates.sort_unstable();
+    if crates != target_crates {
+        return Err(PlanError::Mismatch);
+    }
+
+    /* once there are too many arrangements, the search stops expanding them and gives up */
+    let (explored, gave_up) = (Cell::new(0), Cell::new(false));
+    let neighbors = |stacks: &Vec<VecDeque<char>>| {
+        let mut next = Vec::new();
+        if explored.get() >= MAX_PLAN_STATES {
+            gave_up.set(true);
+            return next;
+        }
+        for from in 0 .. stacks.len() {
+            for to in (0 .. stacks.len()).filter(|&to| to != from) {
+                for amount in 1 ..= stacks[from].len() {
+                    let instruction = Instruction { amount, from, to };
+                    let mut moved = stacks.clone();
+                    move_crates(&mut moved, &instruction, model);
+                    next.push((moved, 1));
+                }
+            }
+        }
+        explored.set(explored.get() + next.len());
+        next
+    };
+    /* every stack that isn't the bottom part of its target needs at least one move away from it */
+    let heuristic = |stacks: &Vec<VecDeque<char>>| stacks.iter()
+                                                         .zip(&target)
+                                                         .filter(|(stack, goal)| stack.len() > goal.len() || stack.iter().zip(goal.iter()).any(|(a, b)| a != b))
+                                                         .count();
+    let path = match search::astar([stacks.to_vec()], neighbors, heuristic, |stacks| !gave_up.get() && *stacks == target) {
+        Some(path) => path,
+        None if gave_up.get() => return rebuild(stacks, &target, model).ok_or(PlanError::TooLarge { crates: crates.len() }),
+        None => return Err(PlanError::Unreachable),
+    };
+
+    /* the stack that shrank and the one that grew between two states make up the instruction */
+    let instructions = path.nodes.windows(2).map(|w| {
+        let from = (0 .. w[0].len()).find(|&idx| w[1][idx].len() < w[0][idx].len()).unwrap();
+        let to = (0 .. w[0].len()).find(|&idx| w[1][idx].len() > w[0][idx].len()).unwrap();
+        Instruction { amount: w[0][from].len() - w[1][from].len(), from, to }
+    });
+    Ok(instructions.collect())
+}
+
+const CRATE_SIZE: usize = 8;
+const CRATE_MARGIN: usize = 1;
+const FLOOR: usize = 2;
+
+/// Size of the frames for `capacity` crates stacked on each of `stacks` stacks.
+pub fn frame_size(stacks: usize, capacity: usize) -> (usize, usize) {
+    let cell = CRATE_SIZE + 2 * CRATE_MARGIN;
+    (stacks * cell, capacity * cell + FLOOR)
+}
+
+/// Draws the stacks as colored boxes, one color per crate name.
+pub fn render_frame(stacks: &[VecDeque<char>], capacity: usize) -> Image {
+    let (width, height) = frame_size(stacks.len(), capacity);
+    let cell = CRATE_SIZE + 2 * CRATE_MARGIN;
+    let mut image = Image::new(width, height, [16, 16, 24]);
+    image.fill_rect(0, height - FLOOR, width, FLOOR, [128, 128, 128]);
+    for (x, stack) in stacks.iter().enumerate() {
+        for (y, &name) in stack.iter().enumerate() {
+            /* spread the names over the color space */
+            let n = name as u32;
+            let color = [(64 + n * 37 % 192) as u8, (64 + n * 71 % 192) as u8, (64 + n * 113 % 192) as u8];
+            let top = height - FLOOR - (y + 1) * cell;
+            image.fill_rect(x * cell + CRATE_MARGIN, top + CRATE_MARGIN, CRATE_SIZE, CRATE_SIZE, color);
+        }
+    }
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "    [D]    ".to_string(),
+            "[N] [C]    ".to_string(),
+            "[Z] [M] [P]".to_string(),
+            " 1   2   3 ".to_string(),
+            "".to_string(),
+            "move 1 from 2 to 1".to_string(),
+            "move 3 from 1 to 3".to_string(),
+            "move 2 from 2 to 1".to_string(),
+            "move 1 from 1 to 2".to_string(),
+        ];
+
+        let cratemover = CrateMover::new(&input);
+        assert_eq!(find_top_crates(&cratemover), Ok("CMZ".to_string()));
+        assert_eq!(find_top_crates_9001(&cratemover), Ok("MCD".to_string()));
+
+        let tops = |steps: Steps| steps.map(|stacks| stacks.iter().map(|s| s.back().copied().unwrap_or(' ')).collect::<String>())
+                                       .collect::<Vec<_>>();
+        assert_eq!(tops(cratemover.steps(Model::CrateMover9000, StepSize::Instruction)), ["NDP", "DCP", " CZ", "M Z", "CMZ"]);
+        assert_eq!(tops(cratemover.steps(Model::CrateMover9001, StepSize::Instruction)), ["NDP", "DCP", " CD", "C D", "MCD"]);
+        assert_eq!(tops(cratemover.steps(Model::CrateMover9000, StepSize::Crate)).len(), 1 + 1 + 3 + 2 + 1);
+        let steps = cratemover.steps(Model::CrateMover9001, StepSize::Crate).collect::<Vec<_>>();
+        /* the 9001 lifts all three crates at once and sets them down one by one */
+        assert_eq!(steps[3], [VecDeque::new(), VecDeque::from(['M', 'C']), VecDeque::from(['P', 'Z', 'N'])]);
+        assert_eq!(steps[4][2], ['P', 'Z', 'N', 'D']);
+
+        assert_eq!(drawing(cratemover.stacks()), input[.. 4].iter().map(|line| format!("{}\n", line)).collect::<String>());
+        assert_eq!(lines(&cratemover.to_string()), input);
+        for model in [Model::CrateMover9000, Model::CrateMover9001] {
+            for stacks in cratemover.steps(model, StepSize::Crate) {
+                assert_eq!(CrateMover::new(&lines(&drawing(&stacks))).stacks(), stacks);
+            }
+        }
+        let sparse = [VecDeque::new(), VecDeque::from(['A']), VecDeque::new()];
+        assert_eq!(drawing(&sparse), "    [A]    \n 1   2   3 \n");
+        assert_eq!(CrateMover::new(&lines(&drawing(&sparse))).stacks(), sparse);
+        let wide = vec![VecDeque::from(['W']); 10];
+        assert_eq!(CrateMover::new(&lines(&drawing(&wide))).stacks(), wide);
+
+        assert_eq!(cratemover.validate(), Ok(()));
+        assert_eq!(cratemover.execute(Model::CrateMover9001).unwrap(), cratemover.steps(Model::CrateMover9001, StepSize::Crate).last().unwrap());
+        let mut broken = input.to_vec();
+        broken.push("move 5 from 3 to 1".to_string());
+        broken.push("move 1 from 0 to 2".to_string());
+        let error = CrateMover::new(&broken).validate().unwrap_err();
+        assert_eq!((error.index, error.line), (4, 10));
+        assert_eq!(error.problem, Problem::NotEnoughCrates { stack: 3, size: 4 });
+        assert_eq!(error.to_string(), "line 10 (instruction 4, \"move 5 from 3 to 1\"): stack 3 only holds 4 crates");
+        assert_eq!(CrateMover::new(&broken).execute(Model::CrateMover9000), Err(error));
+        broken.remove(9);
+        let error = CrateMover::new(&broken).execute(Model::CrateMover9001).unwrap_err();
+        assert_eq!((error.index, error.line, error.problem), (4, 10, Problem::NoSuchStack { stack: 0, stacks: 3 }));
+        broken[9] = "move 1 from 1 to 7".to_string();
+        assert_eq!(CrateMover::new(&broken).validate().unwrap_err().problem, Problem::NoSuchStack { stack: 7, stacks: 3 });
+
+        /* moving crates onto the same stack leaves it unchanged */
+        let same = CrateMover::new(&[
+            "[A]".to_string(),
+            "[B]".to_string(),
+            "[C]".to_string(),
+            " 1 ".to_string(),
+            "".to_string(),
+            "move 2 from 1 to 1".to_string(),
+        ]);
+        for model in [Model::CrateMover9000, Model::CrateMover9001] {
+            assert_eq!(same.execute(model).unwrap(), [VecDeque::from(['C', 'B', 'A'])]);
+            for step_size in [StepSize::Crate, StepSize::Instruction] {
+                assert_eq!(same.steps(model, step_size).last().unwrap(), [VecDeque::from(['C', 'B', 'A'])]);
+            }
+        }
+
+        /* plans that can be fed back in */
+        let target = CrateMover::new(&[
+            "[C]        ".to_string(),
+            "[P] [M]    ".to_string(),
+            "[Z] [N] [D]".to_string(),
+        ]);
+        for (model, length) in [(Model::CrateMover9000, 6), (Model::CrateMover9001, 5)] {
+            let instructions = plan(cratemover.stacks(), target.stacks(), model).unwrap();
+            assert_eq!(instructions.len(), length);
+            let replay = input[.. 5].iter()
+                                    .cloned()
+                                    .chain(instructions.iter().map(|i| i.to_string()))
+                                    .collect::<Vec<_>>();
+            let steps = CrateMover::new(&replay).steps(model, StepSize::Instruction).collect::<Vec<_>>();
+            assert_eq!(steps.last().unwrap(), target.stacks());
+        }
+        /* a plan that empties a stack, fed back in as a puzzle input */
+        let target = CrateMover::new(&lines("[D]        \n[C]     [M]\n[Z]     [N]\n[P]        \n 1   2   3 \n"));
+        for model in [Model::CrateMover9000, Model::CrateMover9001] {
+            let instructions = plan(cratemover.stacks(), target.stacks(), model).unwrap();
+            let replay = drawing(cratemover.stacks()) + "\n" + &instructions.iter().map(|i| format!("{}\n", i)).collect::<String>();
+            let replay = Day5.parse(&replay).unwrap();
+            let part = if model == Model::CrateMover9000 { Day5.part1(&replay) } else { Day5.part2(&replay) };
+            assert_eq!(part.unwrap(), Answer::Text("D M".to_string()));
+        }
+        /* with two stacks, the 9000 can't swap crates */
+        let (stacks, swapped) = ([VecDeque::from(['A', 'B']), VecDeque::new()], [VecDeque::from(['B', 'A'])]);
+        assert_eq!(plan(&stacks, &swapped, Model::CrateMover9000), Err(PlanError::Unreachable));
+        let swap = plan(&stacks, &swapped, Model::CrateMover9001).unwrap();
+        assert_eq!(swap.iter().map(|i| i.to_string()).collect::<Vec<_>>(), ["move 2 from 1 to 2", "move 1 from 2 to 1", "move 1 from 2 to 1"]);
+        assert_eq!(plan(cratemover.stacks(), &[VecDeque::from(['X'])], Model::CrateMover9000), Err(PlanError::Mismatch));
+        assert_eq!(plan(cratemover.stacks(), cratemover.stacks(), Model::CrateMover9001), Ok(vec![]));
+        let many = [('A' ..= 'L').collect::<VecDeque<_>>(), VecDeque::new()];
+        let reversed = [many[0].iter().rev().copied().collect::<VecDeque<_>>()];
+        assert_eq!(plan(&many, &reversed, Model::CrateMover9001), Err(PlanError::TooLarge { crates: 12 }));
+        /* larger warehouses are rebuilt, even with crates of the same name */
+        let stacks = ["ABCAB", "", "CCDE", "FA"].map(|stack| stack.chars().collect::<VecDeque<_>>());
+        let target = ["EDCBA", "AC", "", "FBAC"].map(|stack| stack.chars().collect::<VecDeque<_>>());
+        for model in [Model::CrateMover9000, Model::CrateMover9001] {
+            let instructions = plan(&stacks, &target, model).unwrap();
+            let replay = drawing(&stacks) + "\n" + &instructions.iter().map(|i| format!("{}\n", i)).collect::<String>();
+            assert_eq!(CrateMover::new(&lines(&replay)).execute(model).unwrap(), target);
+        }
+
+        let frame = render_frame(&steps[0], 6);
+        assert_eq!((frame.width(), frame.height()), frame_size(3, 6));
+        assert_eq!((frame.width(), frame.height()), (30, 62));
+        assert_ne!(frame.get(5, 55), frame.get(5, 45));
+        assert_eq!(frame.get(25, 45), frame.get(25, 5));
+    }
+}
@@ -0,0 +1,212 @@
+use std::io::{self, Read};
+use crate::{Answer, Error, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<String, Error> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(&self, input: &String) -> Result<Answer, Error> {
+        Ok(start_of_packet(input).into())
+    }
+
+    fn part2(&self, input: &String) -> Result<Answer, Error> {
+        Ok(start_of_message(input).into())
+    }
+}
+
+/// End of a window of `length` distinct bytes; `position` counts the bytes
+/// read up to and including the last byte of the window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Marker {
+    pub length: usize,
+    pub position: u64,
+}
+
+/// Finds markers of several window lengths in one pass, with constant work per byte.
+#[derive(Debug, Clone)]
+pub struct MarkerDetector {
+    lengths: Vec<usize>,
+    /* position after the last occurrence of every byte, 0 if not seen yet */
+    last_seen: [u64; 256],
+    /* start of the longest run of distinct bytes ending at the current byte */
+    run_start: u64,
+    position: u64,
+}
+
+impl MarkerDetector {
+    /// Lengths need to be positive, `markers` and `first_markers` check them for you.
+    pub fn new(lengths: &[usize]) -> MarkerDetector {
+        assert!(lengths.iter().all(|&length| length > 0), "marker length should be positive");
+        let mut lengths = lengths.to_vec();
+        lengths.sort_unstable();
+        lengths.dedup();
+        MarkerDetector { lengths, last_seen: [0; 256], run_start: 0, position: 0 }
+    }
+
+    /// Bytes seen so far.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /* consumes a byte and returns the number of distinct bytes ending with it */
+    fn advance(&mut self, byte: u8) -> u64 {
+        self.run_start = self.run_start.max(self.last_seen[byte as usize]);
+        self.position += 1;
+        self.last_seen[byte as usize] = self.position;
+        self.position - self.run_start
+    }
+
+    /// Consumes the next byte and returns the markers ending with it, shortest first.
+    pub fn push(&mut self, byte: u8) -> impl Iterator<Item = Marker> + '_ {
+        let (run, position) = (self.advance(byte), self.position);
+        self.lengths.iter()
+                    .take_while(move |&&length| length as u64 <= run)
+                    .map(move |&length| Marker { length, position })
+    }
+}
+
+/// Every marker in a byte stream, in the order in which they end. Line terminators
+/// are skipped like `Day6` trims them, so positions count the other bytes only.
+pub struct Markers<R> {
+    reader: R,
+    detector: MarkerDetector,
+    buffer: Vec<u8>,
+    filled: usize,
+    next: usize,
+    /* run of distinct bytes ending with the previous byte, and the next marker length to report for it */
+    run: u64,
+    pending: usize,
+}
+
+/// Fails with `InvalidInput` if a length is 0.
+pub fn markers<R: Read>(reader: R, lengths: &[usize]) -> io::Result<Markers<R>> {
+    if lengths.contains(&0) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "marker length should be positive"));
+    }
+    Ok(Markers {
+        reader,
+        detector: MarkerDetector::new(lengths),
+        buffer: vec![0; 1 << 16],
+        filled: 0,
+        next: 0,
+        run: 0,
+        pending: 0,
+    })
+}
+
+impl<R: Read> Iterator for Markers<R> {
+    type Item = io::Result<Marker>;
+
+    fn next(&mut self) -> Option<io::Result<Marker>> {
+        loop {
+            if let Some(&length) = self.detector.lengths.get(self.pending).filter(|&&length| length as u64 <= self.run) {
+                self.pending += 1;
+                return Some(Ok(Marker { length, position: self.detector.position }));
+            }
+            if self.next == self.filled {
+                match self.reader.read(&mut self.buffer) {
+                    Ok(0) => return None,
+                    Ok(n) => (self.filled, self.next) = (n, 0),
+                    Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                    Err(err) => return Some(Err(err)),
+                }
+            }
+            let byte = self.buffer[self.next];
+            self.next += 1;
+            if byte == b'\n' || byte == b'\r' {
+                continue;
+            }
+            self.run = self.detector.advance(byte);
+            self.pending = 0;
+        }
+    }
+}
+
+/// Position of the first marker of every length, read in a single pass that
+/// stops once all were found.
+pub fn first_markers(reader: impl Read, lengths: &[usize]) -> io::Result<Vec<Option<u64>>> {
+    let mut found = vec![None; lengths.len()];
+    for marker in markers(reader, lengths)? {
+        let marker = marker?;
+        for (idx, _) in lengths.iter().enumerate().filter(|&(_, &length)| length == marker.length) {
+            found[idx].get_or_insert(marker.position);
+        }
+        if found.iter().all(Option::is_some) {
+            break;
+        }
+    }
+    Ok(found)
+}
+
+fn start_of_prefix(input: &str, prefix_len: usize) -> u64 {
+    first_markers(input.trim().as_bytes(), &[prefix_len]).expect("reading from memory should not fail")[0]
+        .unwrap_or_else(|| panic!("set should contain {} different characters", prefix_len))
+}
+
+fn start_of_packet(input: &str) -> u64 {
+    start_of_prefix(input, 4)
+}
+
+fn start_of_message(input: &str) -> u64 {
+    start_of_prefix(input, 14)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(start_of_packet("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 7);
+        assert_eq!(start_of_packet("bvwbjplbgvbhsrlpgdmjqwftvncz"), 5);
+        assert_eq!(start_of_packet("nppdvjthqldpwncqszvftbrmjlhg"), 6);
+        assert_eq!(start_of_packet("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 10);
+        assert_eq!(start_of_packet("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 11);
+
+        assert_eq!(start_of_message("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 19);
+        assert_eq!(start_of_message("bvwbjplbgvbhsrlpgdmjqwftvncz"), 23);
+        assert_eq!(start_of_message("nppdvjthqldpwncqszvftbrmjlhg"), 23);
+        assert_eq!(start_of_message("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 29);
+        assert_eq!(start_of_message("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 26);
+
+        let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        assert_eq!(first_markers(input.as_bytes(), &[4, 14, 30]).unwrap(), [Some(7), Some(19), None]);
+        let all = markers(input.as_bytes(), &[14, 4]).unwrap().map(Result::unwrap).collect::<Vec<_>>();
+        assert_eq!(all[.. 3], [
+            Marker { length: 4, position: 7 },
+            Marker { length: 4, position: 8 },
+            Marker { length: 4, position: 9 },
+        ]);
+        assert_eq!(all.iter().filter(|m| m.length == 14).map(|m| m.position).collect::<Vec<_>>(), [19, 25, 26, 27, 28, 29, 30]);
+
+        /* compare with every window checked on its own */
+        let bytes = input.as_bytes();
+        for length in 1 ..= 15 {
+            let expected = (length ..= bytes.len()).filter(|&end| {
+                                                       let window = &bytes[end - length .. end];
+                                                       window.iter().all(|b| window.iter().filter(|c| *c == b).count() == 1)
+                                                   })
+                                                   .map(|end| end as u64)
+                                                   .collect::<Vec<_>>();
+            let found = markers(bytes, &[length]).unwrap().map(|m| m.unwrap().position).collect::<Vec<_>>();
+            assert_eq!(found, expected);
+        }
+
+        /* reads that end in the middle of a window */
+        let mut detector = MarkerDetector::new(&[2]);
+        assert_eq!(detector.push(b'a').count(), 0);
+        assert_eq!(detector.push(b'b').collect::<Vec<_>>(), [Marker { length: 2, position: 2 }]);
+        assert_eq!(detector.push(b'b').count(), 0);
+        assert_eq!(detector.position(), 3);
+        let chunked = (&b"abc"[..]).chain(&b"ab"[..]);
+        assert_eq!(markers(chunked, &[3]).unwrap().map(|m| m.unwrap().position).collect::<Vec<_>>(), [3, 4, 5]);
+        assert_eq!(first_markers(input.as_bytes(), &[4, 0]).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        let lines = markers(&b"abc\r\nd\n"[..], &[4]).unwrap().map(|m| m.unwrap()).collect::<Vec<_>>();
+        assert_eq!(lines, [Marker { length: 4, position: 4 }]);
+    }
+}
@@ -0,0 +1,412 @@
+use std::fmt;
+use crate::{lines, Answer, Error, Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = FileSystem;
+
+    fn parse(&self, input: &str) -> Result<FileSystem, Error> {
+        parse_filesystem(&lines(input))
+    }
+
+    fn part1(&self, filesystem: &FileSystem) -> Result<Answer, Error> {
+        Ok(sum_directories(filesystem, 100000).into())
+    }
+
+    fn part2(&self, filesystem: &FileSystem) -> Result<Answer, Error> {
+        Ok(size_deletable_directory(filesystem, 70000000, 30000000).into())
+    }
+}
+
+enum Command {
+    Cd { directory: String },
+    List,
+}
+
+impl Command {
+    fn new(cmdline: &str) -> Result<Command, String> {
+        if let Some(directory) = cmdline.strip_prefix("cd ") {
+            Ok(Command::Cd { directory: directory.to_string() })
+        } else if cmdline == "ls" {
+            Ok(Command::List)
+        } else {
+            Err("unknown command".to_string())
+        }
+    }
+}
+
+struct CommandResult {
+    command: Command,
+    /* input line of the command, 1-based */
+    line: usize,
+    output: Vec<FsEntry>,
+}
+
+fn parse_commands(input: &[String]) -> Result<Vec<CommandResult>, Error> {
+    let mut commandresults = Vec::<CommandResult>::new();
+    for (idx, line) in input.iter().enumerate() {
+        let error = |message: &str| Error::parse(idx + 1, line, message);
+        if let Some(cmdline) = line.strip_prefix("$ ") {
+            let command = Command::new(cmdline).map_err(|e| error(&e))?;
+            commandresults.push(CommandResult { command, line: idx + 1, output: Vec::new() });
+        } else {
+            /* line is part of the output */
+            let entry = FsEntry::new(line).map_err(|e| error(&e))?;
+            match commandresults.last_mut() {
+                Some(CommandResult { command: Command::List, output, .. }) => output.push(entry),
+                _ => return Err(error("output without ls")),
+            }
+        }
+    }
+    Ok(commandresults)
+}
+
+enum FsEntry {
+    File { name: String, size: usize },
+    Directory { name: String },
+}
+
+impl FsEntry {
+    fn new(line: &str) -> Result<FsEntry, String> {
+        if let Some(name) = line.strip_prefix("dir ") {
+            Ok(FsEntry::Directory { name: name.to_string() })
+        } else if let Some((size, name)) = line.split_once(' ') {
+            let size = size.parse::<usize>().map_err(|e| format!("invalid size: {}", e))?;
+            Ok(FsEntry::File { name: name.to_string(), size })
+        } else {
+            Err("unknown entry".to_string())
+        }
+    }
+}
+
+/// Index of a node in its `FileSystem`.
+pub type NodeId = usize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeKind {
+    File { size: usize },
+    Directory { children: Vec<NodeId> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub name: String,
+    pub parent: Option<NodeId>,
+    pub kind: NodeKind,
+}
+
+impl Node {
+    pub fn is_dir(&self) -> bool {
+        matches!(self.kind, NodeKind::Directory { .. })
+    }
+}
+
+/// Directory tree; nodes are only ever added after their parent,
+/// so every child has a higher id than its directory.
+#[derive(Debug, Clone)]
+pub struct FileSystem {
+    nodes: Vec<Node>,
+}
+
+impl FileSystem {
+    pub const ROOT: NodeId = 0;
+
+    pub fn new() -> FileSystem {
+        let root = Node { name: "/".to_string(), parent: None, kind: NodeKind::Directory { children: Vec::new() } };
+        FileSystem { nodes: vec![root] }
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Ids of all files and directories, the root first.
+    pub fn ids(&self) -> std::ops::Range<NodeId> {
+        0 .. self.nodes.len()
+    }
+
+    pub fn children(&self, dir: NodeId) -> &[NodeId] {
+        match &self.nodes[dir].kind {
+            NodeKind::Directory { children } => children,
+            NodeKind::File { .. } => &[],
+        }
+    }
+
+    pub fn child(&self, dir: NodeId, name: &str) -> Option<NodeId> {
+        self.children(dir).iter().copied().find(|&child| self.nodes[child].name == name)
+    }
+
+    /// Adds an entry to a directory, or returns the existing one with that name.
+    pub fn add(&mut self, dir: NodeId, name: &str, kind: NodeKind) -> NodeId {
+        if let Some(existing) = self.child(dir, name) {
+            return existing;
+        }
+        let id = self.nodes.len();
+        match &mut self.nodes[dir].kind {
+            NodeKind::Directory { children } => children.push(id),
+            NodeKind::File { .. } => panic!("{} is not a directory", self.path(dir)),
+        }
+        self.nodes.push(Node { name: name.to_string(), parent: Some(dir), kind });
+        id
+    }
+
+    /// Finds an absolute path like `/a/e`.
+    pub fn lookup(&self, path: &str) -> Option<NodeId> {
+        path.strip_prefix('/')?
+            .split('/')
+            .filter(|name| !name.is_empty())
+            .try_fold(FileSystem::ROOT, |dir, name| self.child(dir, name))
+    }
+
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names = Vec::new();
+        let mut current = id;
+        while let Some(parent) = self.nodes[current].parent {
+            names.push(self.nodes[current].name.as_str());
+            current = parent;
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    /// Number of directories above the node; 0 for the root.
+    pub fn depth(&self, id: NodeId) -> usize {
+        std::iter::successors(self.nodes[id].parent, |&parent| self.nodes[parent].parent).count()
+    }
+
+    /// Total size of every node, including everything below directories.
+    pub fn totals(&self) -> Vec<usize> {
+        let mut totals = vec![0; self.nodes.len()];
+        /* children come after their parents, so going backwards finishes every directory before its parent */
+        for id in self.ids().rev() {
+            if let NodeKind::File { size } = self.nodes[id].kind {
+                totals[id] = size;
+            }
+            if let Some(parent) = self.nodes[id].parent {
+                totals[parent] += totals[id];
+            }
+        }
+        totals
+    }
+
+    /// `du`-style totals of all directories, each after its subdirectories.
+    pub fn du(&self) -> Vec<(String, usize)> {
+        let totals = self.totals();
+        let mut entries = Vec::new();
+        self.visit_dirs(FileSystem::ROOT, &mut |id| entries.push((self.path(id), totals[id])));
+        entries
+    }
+
+    fn visit_dirs(&self, dir: NodeId, visit: &mut impl FnMut(NodeId)) {
+        for &child in &self.sorted_children(dir) {
+            if self.nodes[child].is_dir() {
+                self.visit_dirs(child, visit);
+            }
+        }
+        visit(dir);
+    }
+
+    fn sorted_children(&self, dir: NodeId) -> Vec<NodeId> {
+        let mut children = self.children(dir).to_vec();
+        children.sort_by(|&a, &b| self.nodes[a].name.cmp(&self.nodes[b].name));
+        children
+    }
+
+    /// The `count` largest files, biggest first.
+    pub fn largest_files(&self, count: usize) -> Vec<NodeId> {
+        let mut files = self.ids()
+                            .filter_map(|id| match self.nodes[id].kind {
+                                NodeKind::File { size } => Some((size, id)),
+                                NodeKind::Directory { .. } => None,
+                            })
+                            .collect::<Vec<_>>();
+        files.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| self.path(a.1).cmp(&self.path(b.1))));
+        files.into_iter().take(count).map(|(_, id)| id).collect()
+    }
+
+    /// The `count` nodes nested the deepest, deepest first.
+    pub fn deepest_paths(&self, count: usize) -> Vec<NodeId> {
+        let mut nodes = self.ids().map(|id| (self.depth(id), self.path(id), id)).collect::<Vec<_>>();
+        nodes.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+        nodes.into_iter().take(count).map(|(_, _, id)| id).collect()
+    }
+
+    fn write_tree(&self, f: &mut fmt::Formatter, totals: &[usize], dir: NodeId, indent: &str) -> fmt::Result {
+        let children = self.sorted_children(dir);
+        for (idx, &child) in children.iter().enumerate() {
+            let last = idx + 1 == children.len();
+            let node = &self.nodes[child];
+            let name = if node.is_dir() { format!("{}/", node.name) } else { node.name.clone() };
+            writeln!(f, "{}{} {} ({})", indent, if last { "└──" } else { "├──" }, name, totals[child])?;
+            if node.is_dir() {
+                self.write_tree(f, totals, child, &format!("{}{}", indent, if last { "    " } else { "│   " }))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for FileSystem {
+    fn default() -> FileSystem {
+        FileSystem::new()
+    }
+}
+
+impl fmt::Display for FileSystem {
+    /// Draws the tree like `tree`, with the total size behind every entry.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let totals = self.totals();
+        writeln!(f, "/ ({})", totals[FileSystem::ROOT])?;
+        self.write_tree(f, &totals, FileSystem::ROOT, "")
+    }
+}
+
+fn eval_program(commandresults: &[CommandResult], input: &[String]) -> Result<FileSystem, Error> {
+    let mut filesystem = FileSystem::new();
+    let mut cwd = FileSystem::ROOT;
+    for CommandResult{command, line, output} in commandresults {
+        match command {
+            Command::Cd{ directory } if directory == "/" => { cwd = FileSystem::ROOT },
+            Command::Cd{ directory } if directory == ".." => { cwd = filesystem.node(cwd).parent.unwrap_or(FileSystem::ROOT) },
+            Command::Cd{ directory } => {
+                let dir = filesystem.add(cwd, directory, NodeKind::Directory { children: Vec::new() });
+                if !filesystem.node(dir).is_dir() {
+                    let message = format!("{} is not a directory", filesystem.path(dir));
+                    return Err(Error::parse(*line, &input[line - 1], message));
+                }
+                cwd = dir;
+            },
+            Command::List => {
+                for entry in output {
+                    match entry {
+                        FsEntry::File { name, size } => filesystem.add(cwd, name, NodeKind::File { size: *size }),
+                        FsEntry::Directory { name } => filesystem.add(cwd, name, NodeKind::Directory { children: Vec::new() }),
+                    };
+                }
+            },
+        }
+    }
+    Ok(filesystem)
+}
+
+/// Rebuilds the file system from the terminal output of `cd` and `ls` commands.
+pub fn parse_filesystem(input: &[String]) -> Result<FileSystem, Error> {
+    eval_program(&parse_commands(input)?, input)
+}
+
+/* total sizes of all directories */
+fn directory_sizes(filesystem: &FileSystem) -> Vec<usize> {
+    let totals = filesystem.totals();
+    filesystem.ids()
+              .filter(|&id| filesystem.node(id).is_dir())
+              .map(|id| totals[id])
+              .collect()
+}
+
+fn sum_directories(filesystem: &FileSystem, max_size: usize) -> usize {
+    directory_sizes(filesystem).into_iter()
+                               .filter(|&size| size <= max_size)
+                               .sum()
+}
+
+fn size_deletable_directory(filesystem: &FileSystem, total_size: usize, needed_size: usize) -> usize {
+    let max_size = total_size - needed_size;
+    let need_to_free = filesystem.totals()[FileSystem::ROOT] - max_size;
+
+    directory_sizes(filesystem).into_iter()
+                               .filter(|&size| size > need_to_free)
+                               .min()
+                               .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "$ cd /",
+            "$ ls",
+            "dir a",
+            "14848514 b.txt",
+            "8504156 c.dat",
+            "dir d",
+            "$ cd a",
+            "$ ls",
+            "dir e",
+            "29116 f",
+            "2557 g",
+            "62596 h.lst",
+            "$ cd e",
+            "$ ls",
+            "584 i",
+            "$ cd ..",
+            "$ cd ..",
+            "$ cd d",
+            "$ ls",
+            "4060174 j",
+            "8033020 d.log",
+            "5626152 d.ext",
+            "7214296 k",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+
+        let filesystem = parse_filesystem(&input).unwrap();
+        assert_eq!(sum_directories(&filesystem, 100000), 95437);
+        assert_eq!(size_deletable_directory(&filesystem, 70000000, 30000000), 24933642);
+
+        let e = filesystem.lookup("/a/e").unwrap();
+        assert_eq!(filesystem.path(e), "/a/e");
+        assert_eq!(filesystem.depth(e), 2);
+        assert_eq!(filesystem.node(e).parent, filesystem.lookup("/a"));
+        assert_eq!(filesystem.lookup("/"), Some(FileSystem::ROOT));
+        assert_eq!(filesystem.lookup("/a/x"), None);
+        assert_eq!(filesystem.lookup("d/k"), None);
+        assert_eq!(filesystem.node(filesystem.lookup("/d/k").unwrap()).kind, NodeKind::File { size: 7214296 });
+
+        assert_eq!(filesystem.du(), [
+            ("/a/e".to_string(), 584),
+            ("/a".to_string(), 94853),
+            ("/d".to_string(), 24933642),
+            ("/".to_string(), 48381165),
+        ]);
+        let paths = |ids: Vec<NodeId>| ids.into_iter().map(|id| filesystem.path(id)).collect::<Vec<_>>();
+        assert_eq!(paths(filesystem.largest_files(2)), ["/b.txt", "/c.dat"]);
+        assert_eq!(paths(filesystem.deepest_paths(3)), ["/a/e/i", "/a/e", "/a/f"]);
+
+        assert_eq!(filesystem.to_string(), [
+            "/ (48381165)",
+            "├── a/ (94853)",
+            "│   ├── e/ (584)",
+            "│   │   └── i (584)",
+            "│   ├── f (29116)",
+            "│   ├── g (2557)",
+            "│   └── h.lst (62596)",
+            "├── b.txt (14848514)",
+            "├── c.dat (8504156)",
+            "└── d/ (24933642)",
+            "    ├── d.ext (5626152)",
+            "    ├── d.log (8033020)",
+            "    ├── j (4060174)",
+            "    └── k (7214296)",
+            "",
+        ].join("\n"));
+
+        let error = |lines: &[&str]| parse_filesystem(&lines.iter().map(|&x| String::from(x)).collect::<Vec<_>>()).unwrap_err().to_string();
+        assert_eq!(error(&["$ cd /", "$ rm -rf a"]), "line 2: unknown command (\"$ rm -rf a\")");
+        assert_eq!(error(&["$ ls", "dir a", "12kb b"]), "line 3: invalid size: invalid digit found in string (\"12kb b\")");
+        assert_eq!(error(&["$ ls", "total"]), "line 2: unknown entry (\"total\")");
+        assert_eq!(error(&["dir a"]), "line 1: output without ls (\"dir a\")");
+        assert_eq!(error(&["$ ls", "100 a", "$ cd a", "$ ls"]), "line 3: /a is not a directory (\"$ cd a\")");
+        assert_eq!(parse_filesystem(&[]).unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use crate::grid::Grid;
+use crate::{lines, Answer, Error, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid<usize>;
+
+    fn parse(&self, input: &str) -> Result<Grid<usize>, Error> {
+        Ok(parse_map(&lines(input)))
+    }
+
+    fn part1(&self, map: &Grid<usize>) -> Result<Answer, Error> {
+        Ok(visible_trees(map).into())
+    }
+
+    fn part2(&self, map: &Grid<usize>) -> Result<Answer, Error> {
+        Ok(highest_scenic_score(map).into())
+    }
+}
+
+#[derive(Default, Debug)]
+struct NeighborVisibility {
+    up: usize,
+    down: usize,
+    left: usize,
+    right: usize,
+}
+
+fn parse_map(input: &[String]) -> Grid<usize> {
+    Grid::parse(input, |x| x.to_digit(10).unwrap() as usize)
+}
+
+fn visible_trees(map: &Grid<usize>) -> usize {
+    let mut visibilities = HashMap::<_,NeighborVisibility>::new();
+
+    /* visibilities from top to bottom */
+    for x in 1 .. map.width() - 1 {
+        let mut current_vis = map[(x, 0)];
+        for y in 1 .. map.height() - 1 {
+            let vis_entry = visibilities.entry((x,y)).or_default();
+            vis_entry.up = current_vis;
+            current_vis = current_vis.max(map[(x, y)]);
+        }
+    }
+    /* visibilities from bottom to top */
+    for x in 1 .. map.width() - 1 {
+        let mut current_vis = map[(x, map.height()-1)];
+        for y in (1 .. map.height() - 1).rev() {
+            let vis_entry = visibilities.entry((x,y)).or_default();
+            vis_entry.down = current_vis;
+            current_vis = current_vis.max(map[(x, y)]);
+        }
+    }
+    /* visibilities from left to right */
+    for y in 1 .. map.height() - 1 {
+        let mut current_vis = map[(0, y)];
+        for x in 1 .. map.width() - 1 {
+            let vis_entry = visibilities.entry((x,y)).or_default();
+            vis_entry.left = current_vis;
+            current_vis = current_vis.max(map[(x, y)]);
+        }
+    }
+    /* visibilities from right to left */
+    for y in 1 .. map.height() - 1 {
+        let mut current_vis = map[(map.width()-1, y)];
+        for x in (1 .. map.width() - 1).rev() {
+            let vis_entry = visibilities.entry((x,y)).or_default();
+            vis_entry.right = current_vis;
+            current_vis = current_vis.max(map[(x, y)]);
+        }
+    }
+
+    let mut total_visible = 2 * (map.height() - 1) + 2 * (map.width() - 1);
+    for (pos, visibility) in visibilities {
+        let height = map[pos];
+        if height > visibility.up || height > visibility.down || height > visibility.left || height > visibility.right {
+            total_visible += 1;
+        }
+    }
+    total_visible
+}
+
+fn highest_scenic_score(map: &Grid<usize>) -> usize {
+    let mut visibilities = HashMap::<_,NeighborVisibility>::new();
+
+    let update_range_vector = |range: &mut [usize; 10], height: usize|{
+        /* all heights less than the specified one will start with a
+           new visibility of one; for greater heights the distance
+           increases by 1. */
+        for (i, val) in range.iter_mut().enumerate() {
+            if i <= height {
+                *val = 1;
+            } else {
+                *val += 1;
+            }
+        }
+    };
+
+    /* visibilities from top to bottom */
+    for x in 0 .. map.width() {
+        let mut ranges = [0; 10];
+        for y in 0 .. map.height() {
+            let height = map[(x, y)];
+            let vis_entry = visibilities.entry((x,y)).or_default();
+            vis_entry.up = ranges[height];
+            update_range_vector(&mut ranges, height);
+        }
+    }
+    /* visibilities from bottom to top */
+    for x in 0 .. map.width() {
+        let mut ranges = [0; 10];
+        for y in (0 .. map.height()).rev() {
+            let height = map[(x, y)];
+            let vis_entry = visibilities.entry((x,y)).or_default();
+            vis_entry.down = ranges[height];
+            update_range_vector(&mut ranges, height);
+        }
+    }
+    /* visibilities from left to right */
+    for y in 0 .. map.height() {
+        let mut ranges = [0; 10];
+        for x in 0 .. map.width() {
+            let height = map[(x, y)];
+            let vis_entry = visibilities.entry((x,y)).or_default();
+            vis_entry.left = ranges[height];
+            update_range_vector(&mut ranges, height);
+        }
+    }
+    /* visibilities from right to left */
+    for y in 0 .. map.height() {
+        let mut ranges = [0; 10];
+        for x in (0 .. map.width()).rev() {
+            let height = map[(x, y)];
+            let vis_entry = visibilities.entry((x,y)).or_default();
+            vis_entry.right = ranges[height];
+            update_range_vector(&mut ranges, height);
+        }
+    }
+
+    visibilities.values()
+                .map(|v| v.up * v.down * v.left * v.right)
+                .max()
+                .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "30373",
+            "25512",
+            "65332",
+            "33549",
+            "35390",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+
+        let map = parse_map(&input);
+        assert_eq!(visible_trees(&map), 21);
+        assert_eq!(highest_scenic_score(&map), 8);
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashSet;
+use crate::geom::{Dir4, Point2};
+use crate::{lines, Answer, Error, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Movement>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Movement>, Error> {
+        Ok(parse_movements(&lines(input)))
+    }
+
+    fn part1(&self, movements: &Vec<Movement>) -> Result<Answer, Error> {
+        Ok(tail_positions(movements, 2).into())
+    }
+
+    fn part2(&self, movements: &Vec<Movement>) -> Result<Answer, Error> {
+        Ok(tail_positions(movements, 10).into())
+    }
+}
+
+pub struct Movement {
+    direction: Dir4,
+    distance: isize,
+}
+
+impl Movement {
+    fn new(input: &str) -> Movement {
+        let mut it = input.split_whitespace();
+        let direction = it.next().unwrap().parse().unwrap();
+        let distance = it.next().unwrap().parse().unwrap();
+        Movement { direction, distance }
+    }
+}
+
+fn follow(knot: &mut Point2, other: &Point2) {
+    if knot.chebyshev(other) <= 1 {
+        return;
+    }
+    /* move one step closer on both axes, diagonally if needed */
+    knot.x += (other.x - knot.x).signum();
+    knot.y += (other.y - knot.y).signum();
+}
+
+fn parse_movements(input: &[String]) -> Vec<Movement> {
+    input.iter()
+         .map(|x| Movement::new(x))
+         .collect()
+}
+
+fn tail_positions(movements: &[Movement], knot_count: usize) -> usize {
+    let mut visited = HashSet::new();
+    let mut knots = vec![Point2::default(); knot_count];
+    visited.insert(knots[0]);
+    for movement in movements {
+        for _ in 0 .. movement.distance {
+            knots[0] = knots[0].step(movement.direction);
+            for idx in 1 .. knot_count {
+                let new_pos = knots[idx-1];
+                follow(&mut knots[idx], &new_pos);
+            }
+            visited.insert(knots[knot_count-1]);
+        }
+    }
+    visited.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = [
+            "R 4",
+            "U 4",
+            "L 3",
+            "D 1",
+            "R 4",
+            "D 1",
+            "L 5",
+            "R 2",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+
+        let movements = parse_movements(&input);
+        assert_eq!(tail_positions(&movements, 2), 13);
+        assert_eq!(tail_positions(&movements, 10), 1);
+
+        let input = [
+            "R 5",
+            "U 8",
+            "L 8",
+            "D 3",
+            "R 17",
+            "D 10",
+            "L 25",
+            "U 20",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+
+        assert_eq!(tail_positions(&parse_movements(&input), 10), 36);
+    }
+}
@@ -0,0 +1,64 @@
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use crate::DynSolution;
+
+pub struct Day {
+    pub day: u8,
+    pub solution: &'static (dyn DynSolution + Sync),
+}
+
+pub static DAYS: &[Day] = &[
+    Day { day: 1, solution: &day1::Day1 },
+    Day { day: 2, solution: &day2::Day2 },
+    Day { day: 3, solution: &day3::Day3 },
+    Day { day: 4, solution: &day4::Day4 },
+    Day { day: 5, solution: &day5::Day5 },
+    Day { day: 6, solution: &day6::Day6 },
+    Day { day: 7, solution: &day7::Day7 },
+    Day { day: 8, solution: &day8::Day8 },
+    Day { day: 9, solution: &day9::Day9 },
+    Day { day: 10, solution: &day10::Day10 },
+    Day { day: 11, solution: &day11::Day11 },
+    Day { day: 12, solution: &day12::Day12 },
+    Day { day: 13, solution: &day13::Day13 },
+    Day { day: 14, solution: &day14::Day14 },
+    Day { day: 15, solution: &day15::Day15 },
+    Day { day: 16, solution: &day16::Day16 },
+    Day { day: 17, solution: &day17::Day17 },
+    Day { day: 18, solution: &day18::Day18 },
+    Day { day: 19, solution: &day19::Day19 },
+    Day { day: 20, solution: &day20::Day20 },
+    Day { day: 21, solution: &day21::Day21 },
+    Day { day: 22, solution: &day22::Day22 },
+    Day { day: 23, solution: &day23::Day23 },
+    Day { day: 24, solution: &day24::Day24 },
+    Day { day: 25, solution: &day25::Day25 },
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
@@ -0,0 +1,307 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+use std::str::FromStr;
+
+/// Position on a 2D map; `y` grows downwards as in the puzzle inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point2 {
+    pub const fn new(x: isize, y: isize) -> Point2 {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(&self, other: &Point2) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(&self, other: &Point2) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// The neighbouring point in `direction`.
+    pub fn step(&self, direction: impl Into<Dir8>) -> Point2 {
+        *self + direction.into().offset()
+    }
+
+    /// Horizontally and vertically adjacent points.
+    pub fn neighbors4(&self) -> [Point2; 4] {
+        Dir4::ALL.map(|d| self.step(d))
+    }
+
+    /// Adjacent points including diagonals.
+    pub fn neighbors8(&self) -> [Point2; 8] {
+        Dir8::ALL.map(|d| self.step(d))
+    }
+}
+
+impl From<(isize, isize)> for Point2 {
+    fn from((x, y): (isize, isize)) -> Point2 {
+        Point2 { x, y }
+    }
+}
+
+impl From<(usize, usize)> for Point2 {
+    fn from((x, y): (usize, usize)) -> Point2 {
+        Point2 { x: x as isize, y: y as isize }
+    }
+}
+
+impl From<Point2> for (isize, isize) {
+    fn from(p: Point2) -> (isize, isize) {
+        (p.x, p.y)
+    }
+}
+
+impl Add for Point2 {
+    type Output = Point2;
+
+    fn add(self, other: Point2) -> Point2 {
+        Point2 { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Point2;
+
+    fn sub(self, other: Point2) -> Point2 {
+        Point2 { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl Mul<isize> for Point2 {
+    type Output = Point2;
+
+    fn mul(self, factor: isize) -> Point2 {
+        Point2 { x: self.x * factor, y: self.y * factor }
+    }
+}
+
+impl AddAssign for Point2 {
+    fn add_assign(&mut self, other: Point2) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point2 {
+    fn sub_assign(&mut self, other: Point2) {
+        *self = *self - other;
+    }
+}
+
+/// Position in 3D space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+impl Point3 {
+    pub const fn new(x: isize, y: isize, z: isize) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: &Point3) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    pub fn chebyshev(&self, other: &Point3) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y)).max(self.z.abs_diff(other.z))
+    }
+
+    /// The six points sharing a face with this one.
+    pub fn neighbors6(&self) -> [Point3; 6] {
+        let Point3 { x, y, z } = *self;
+        [
+            Point3::new(x + 1, y, z),
+            Point3::new(x - 1, y, z),
+            Point3::new(x, y + 1, z),
+            Point3::new(x, y - 1, z),
+            Point3::new(x, y, z + 1),
+            Point3::new(x, y, z - 1),
+        ]
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, other: Point3) -> Point3 {
+        Point3 { x: self.x + other.x, y: self.y + other.y, z: self.z + other.z }
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, other: Point3) -> Point3 {
+        Point3 { x: self.x - other.x, y: self.y - other.y, z: self.z - other.z }
+    }
+}
+
+/// One of the four directions on a map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// All directions, clockwise starting upwards.
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    /// Parses `U/D/L/R`, `^v<>` and `N/S/E/W`.
+    pub fn from_char(c: char) -> Option<Dir4> {
+        match c {
+            'U' | '^' | 'N' => Some(Dir4::Up),
+            'D' | 'v' | 'S' => Some(Dir4::Down),
+            'L' | '<' | 'W' => Some(Dir4::Left),
+            'R' | '>' | 'E' => Some(Dir4::Right),
+            _ => None,
+        }
+    }
+
+    fn rotate(&self, quarters: usize) -> Dir4 {
+        Dir4::ALL[(*self as usize + quarters) % 4]
+    }
+
+    pub fn turn_left(&self) -> Dir4 {
+        self.rotate(3)
+    }
+
+    pub fn turn_right(&self) -> Dir4 {
+        self.rotate(1)
+    }
+
+    pub fn opposite(&self) -> Dir4 {
+        self.rotate(2)
+    }
+
+    /// Offset of a single step in this direction.
+    pub fn offset(&self) -> Point2 {
+        Dir8::from(*self).offset()
+    }
+}
+
+/// Error returned when parsing a direction fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirError(String);
+
+impl fmt::Display for ParseDirError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid direction: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirError {}
+
+impl FromStr for Dir4 {
+    type Err = ParseDirError;
+
+    fn from_str(s: &str) -> Result<Dir4, ParseDirError> {
+        let mut chars = s.chars();
+        match (chars.next().and_then(Dir4::from_char), chars.next()) {
+            (Some(direction), None) => Ok(direction),
+            _ => Err(ParseDirError(s.to_string())),
+        }
+    }
+}
+
+/// One of the eight directions on a map, including diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    /// All directions, clockwise starting upwards.
+    pub const ALL: [Dir8; 8] = [Dir8::N, Dir8::NE, Dir8::E, Dir8::SE, Dir8::S, Dir8::SW, Dir8::W, Dir8::NW];
+
+    fn rotate(&self, eighths: usize) -> Dir8 {
+        Dir8::ALL[(*self as usize + eighths) % 8]
+    }
+
+    /// Turns by 45 degrees.
+    pub fn turn_left(&self) -> Dir8 {
+        self.rotate(7)
+    }
+
+    /// Turns by 45 degrees.
+    pub fn turn_right(&self) -> Dir8 {
+        self.rotate(1)
+    }
+
+    pub fn opposite(&self) -> Dir8 {
+        self.rotate(4)
+    }
+
+    pub fn offset(&self) -> Point2 {
+        match self {
+            Dir8::N => Point2::new(0, -1),
+            Dir8::NE => Point2::new(1, -1),
+            Dir8::E => Point2::new(1, 0),
+            Dir8::SE => Point2::new(1, 1),
+            Dir8::S => Point2::new(0, 1),
+            Dir8::SW => Point2::new(-1, 1),
+            Dir8::W => Point2::new(-1, 0),
+            Dir8::NW => Point2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(direction: Dir4) -> Dir8 {
+        match direction {
+            Dir4::Up => Dir8::N,
+            Dir4::Right => Dir8::E,
+            Dir4::Down => Dir8::S,
+            Dir4::Left => Dir8::W,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let p = Point2::new(1, 2);
+        assert_eq!(p.manhattan(&Point2::new(-2, 4)), 5);
+        assert_eq!(p.chebyshev(&Point2::new(-2, 4)), 3);
+        assert_eq!(p.step(Dir4::Up), Point2::new(1, 1));
+        assert_eq!(p.step(Dir8::SW), Point2::new(0, 3));
+        assert_eq!(p + Dir4::Left.offset() * 3, Point2::new(-2, 2));
+        assert!(p.neighbors8().iter().all(|n| n.chebyshev(&p) == 1));
+        assert_eq!(p.neighbors4().iter().filter(|n| n.manhattan(&p) == 1).count(), 4);
+        assert_eq!(Point3::new(1, 2, 3).manhattan(&Point3::new(0, 0, 0)), 6);
+        assert!(Point3::default().neighbors6().iter().all(|n| n.manhattan(&Point3::default()) == 1));
+
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Left.turn_right(), Dir4::Up);
+        assert_eq!(Dir4::Right.opposite(), Dir4::Left);
+        assert_eq!(Dir8::N.turn_left(), Dir8::NW);
+        assert_eq!(Dir8::NE.opposite(), Dir8::SW);
+        assert_eq!(Dir8::ALL.map(|d| d.turn_right().turn_left()), Dir8::ALL);
+
+        assert_eq!("U".parse(), Ok(Dir4::Up));
+        assert_eq!("v".parse(), Ok(Dir4::Down));
+        assert_eq!("W".parse(), Ok(Dir4::Left));
+        assert_eq!(Dir4::from_char('>'), Some(Dir4::Right));
+        assert_eq!("RR".parse::<Dir4>(), Err(ParseDirError("RR".to_string())));
+        assert!("x".parse::<Dir4>().is_err());
+    }
+}
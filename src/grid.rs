@@ -0,0 +1,303 @@
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+use crate::geom::Point2;
+
+const OFFSETS_4: [(isize, isize); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+const OFFSETS_8: [(isize, isize); 8] = [(1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1), (0, -1), (1, -1)];
+
+/// Dense two-dimensional map, stored row by row.
+/// Positions are `(x, y)`, with `y` growing downwards as in the puzzle inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> where T: Clone {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Builds a grid from input lines, mapping every character.
+    /// Shorter lines are padded as if they were filled with spaces.
+    pub fn parse(input: &[String], mut f: impl FnMut(char) -> T) -> Grid<T> {
+        let width = input.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+        let mut cells = Vec::with_capacity(width * input.len());
+        for line in input {
+            let len = line.chars().count();
+            cells.extend(line.chars().chain(std::iter::repeat_n(' ', width - len)).map(&mut f));
+        }
+        Grid { width, height: input.len(), cells }
+    }
+
+    /// Grid of the same size with every cell mapped.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, (x, y): (isize, isize)) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn get(&self, pos: (isize, isize)) -> Option<&T> {
+        if self.in_bounds(pos) {
+            Some(&self.cells[pos.1 as usize * self.width + pos.0 as usize])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: (isize, isize)) -> Option<&mut T> {
+        if self.in_bounds(pos) {
+            Some(&mut self.cells[pos.1 as usize * self.width + pos.0 as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Appends a row at the bottom; it has to be as wide as the grid.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) {
+        let len = self.cells.len();
+        self.cells.extend(row);
+        if self.height == 0 {
+            self.width = self.cells.len();
+        }
+        assert_eq!(self.cells.len() - len, self.width, "row should have the width of the grid");
+        self.height += 1;
+    }
+
+    fn neighbors<'a>(&'a self, (x, y): (usize, usize), offsets: &'a [(isize, isize)]) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter()
+               .map(move |&(dx, dy)| (x as isize + dx, y as isize + dy))
+               .filter(|&pos| self.in_bounds(pos))
+               .map(|(x, y)| (x as usize, y as usize))
+    }
+
+    /// Horizontally and vertically adjacent positions inside the grid.
+    pub fn neighbors4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(pos, &OFFSETS_4)
+    }
+
+    /// Adjacent positions inside the grid, including diagonals.
+    pub fn neighbors8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(pos, &OFFSETS_8)
+    }
+
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells[y * self.width .. (y + 1) * self.width].iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0 .. self.height).flat_map(move |y| (0 .. width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Renders the grid as text, one line per row.
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows().take(self.height) {
+            text.extend(row.iter().map(&f));
+            text.push('\n');
+        }
+        text
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width && y < self.height, "position ({}, {}) outside of grid", x, y);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width && y < self.height, "position ({}, {}) outside of grid", x, y);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T> Index<Point2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point2) -> &T {
+        self.get(pos.into()).unwrap_or_else(|| panic!("position ({}, {}) outside of grid", pos.x, pos.y))
+    }
+}
+
+impl<T> IndexMut<Point2> for Grid<T> {
+    fn index_mut(&mut self, pos: Point2) -> &mut T {
+        self.get_mut(pos.into()).unwrap_or_else(|| panic!("position ({}, {}) outside of grid", pos.x, pos.y))
+    }
+}
+
+/// Map of an unbounded world, only storing the occupied positions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(isize, isize), T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid { cells: HashMap::new() }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid::default()
+    }
+
+    /// Builds a grid from input lines; characters mapped to `None` stay empty.
+    pub fn parse(input: &[String], mut f: impl FnMut(char) -> Option<T>) -> SparseGrid<T> {
+        let mut grid = SparseGrid::new();
+        for (y, line) in input.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if let Some(value) = f(c) {
+                    grid.insert((x as isize, y as isize), value);
+                }
+            }
+        }
+        grid
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: (isize, isize)) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: (isize, isize)) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn insert(&mut self, pos: (isize, isize), value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: (isize, isize)) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((isize, isize), &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (isize, isize)> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// Horizontally and vertically adjacent positions, occupied or not.
+    pub fn neighbors4(&self, (x, y): (isize, isize)) -> impl Iterator<Item = (isize, isize)> {
+        OFFSETS_4.iter().map(move |&(dx, dy)| (x + dx, y + dy))
+    }
+
+    /// Adjacent positions including diagonals, occupied or not.
+    pub fn neighbors8(&self, (x, y): (isize, isize)) -> impl Iterator<Item = (isize, isize)> {
+        OFFSETS_8.iter().map(move |&(dx, dy)| (x + dx, y + dy))
+    }
+
+    /// Smallest and largest occupied coordinates, `None` for an empty grid.
+    pub fn bounds(&self) -> Option<((isize, isize), (isize, isize))> {
+        let min_x = self.cells.keys().map(|p| p.0).min()?;
+        let min_y = self.cells.keys().map(|p| p.1).min()?;
+        let max_x = self.cells.keys().map(|p| p.0).max()?;
+        let max_y = self.cells.keys().map(|p| p.1).max()?;
+        Some(((min_x, min_y), (max_x, max_y)))
+    }
+
+    /// Renders the occupied bounding box as text, one line per row.
+    pub fn render(&self, f: impl Fn(Option<&T>) -> char) -> String {
+        let mut text = String::new();
+        if let Some(((min_x, min_y), (max_x, max_y))) = self.bounds() {
+            for y in min_y ..= max_y {
+                text.extend((min_x ..= max_x).map(|x| f(self.get((x, y)))));
+                text.push('\n');
+            }
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid() {
+        let input = [
+            "123",
+            "45",
+            "789",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+
+        let mut grid = Grid::parse(&input, |c| c.to_digit(10).unwrap_or(0));
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[(1, 2)], 8);
+        assert_eq!(grid[Point2::new(0, 1)], 4);
+        assert_eq!(grid.get((2, 1)), Some(&0));
+        assert_eq!(grid.get((-1, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), [4, 5, 0]);
+        assert_eq!(grid.column(2).rev().copied().collect::<Vec<_>>(), [9, 0, 3]);
+        assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8((2, 2)).count(), 3);
+
+        grid[(2, 1)] = 6;
+        *grid.get_mut((0, 0)).unwrap() = 0;
+        grid.push_row([1, 1, 1]);
+        assert_eq!(grid.height(), 4);
+        assert_eq!(grid.iter().filter(|&(_, &n)| n == 1).count(), 3);
+        assert_eq!(grid.render(|&n| char::from_digit(n, 10).unwrap()), "023\n456\n789\n111\n");
+        assert_eq!(grid.map(|&n| n > 5).iter().filter(|&(_, &big)| big).count(), 4);
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let input = [
+            "#..",
+            "..#",
+        ].iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+
+        let mut grid = SparseGrid::parse(&input, |c| (c == '#').then_some(()));
+        assert_eq!(grid.len(), 2);
+        assert!(grid.contains((2, 1)));
+        grid.insert((-2, -1), ());
+        assert_eq!(grid.bounds(), Some(((-2, -1), (2, 1))));
+        assert_eq!(grid.render(|c| if c.is_some() { '#' } else { '.' }), "#....\n..#..\n....#\n");
+        assert_eq!(grid.neighbors8((0, 0)).filter(|&p| grid.contains(p)).count(), 0);
+        assert_eq!(grid.remove((2, 1)), Some(()));
+        assert!(!grid.contains((2, 1)));
+    }
+}
@@ -0,0 +1,120 @@
+use std::io::{self, Write};
+
+pub type Rgb = [u8; 3];
+
+/// RGB picture, e.g. a frame of a visualization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Image {
+        Image { width, height, pixels: vec![background; width * height] }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        if x < self.width && y < self.height { Some(self.pixels[y * self.width + x]) } else { None }
+    }
+
+    /// Colors a pixel; positions outside of the image are ignored.
+    pub fn set(&mut self, x: usize, y: usize, color: Rgb) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = color;
+        }
+    }
+
+    /// Colors a rectangle, clipped to the image.
+    pub fn fill_rect(&mut self, x: usize, y: usize, width: usize, height: usize, color: Rgb) {
+        for py in y .. (y + height).min(self.height) {
+            for px in x .. (x + width).min(self.width) {
+                self.pixels[py * self.width + px] = color;
+            }
+        }
+    }
+
+    /// Writes the image as binary PPM (P6).
+    pub fn write_ppm(&self, out: &mut impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.pixels.concat())
+    }
+}
+
+/// Writes frames as uncompressed YUV4MPEG2 video, which players and encoders read directly.
+pub struct Y4mWriter<W: Write> {
+    out: W,
+    width: usize,
+    height: usize,
+}
+
+impl<W: Write> Y4mWriter<W> {
+    pub fn new(mut out: W, width: usize, height: usize, fps: u32) -> io::Result<Y4mWriter<W>> {
+        writeln!(out, "YUV4MPEG2 W{} H{} F{}:1 Ip A1:1 C444", width, height, fps)?;
+        Ok(Y4mWriter { out, width, height })
+    }
+
+    /// Appends a frame; it has to have the size given to `new`.
+    pub fn write_frame(&mut self, image: &Image) -> io::Result<()> {
+        assert_eq!((image.width, image.height), (self.width, self.height), "frame size should not change");
+
+        /* BT.601 in studio range, one plane after the other */
+        let mut planes = [(); 3].map(|_| Vec::with_capacity(image.pixels.len()));
+        for &[r, g, b] in &image.pixels {
+            let (r, g, b) = (r as i32, g as i32, b as i32);
+            planes[0].push((((66 * r + 129 * g + 25 * b + 128) >> 8) + 16) as u8);
+            planes[1].push((((-38 * r - 74 * g + 112 * b + 128) >> 8) + 128) as u8);
+            planes[2].push((((112 * r - 94 * g - 18 * b + 128) >> 8) + 128) as u8);
+        }
+        self.out.write_all(b"FRAME\n")?;
+        self.out.write_all(&planes.concat())
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let mut image = Image::new(3, 2, [0, 0, 0]);
+        image.fill_rect(1, 1, 5, 5, [255, 255, 255]);
+        image.set(0, 0, [255, 0, 0]);
+        image.set(7, 7, [255, 0, 0]);
+        assert_eq!(image.get(0, 0), Some([255, 0, 0]));
+        assert_eq!(image.get(2, 1), Some([255, 255, 255]));
+        assert_eq!(image.get(1, 0), Some([0, 0, 0]));
+        assert_eq!(image.get(3, 0), None);
+
+        let mut ppm = Vec::new();
+        image.write_ppm(&mut ppm).unwrap();
+        assert!(ppm.starts_with(b"P6\n3 2\n255\n"));
+        assert_eq!(&ppm[ppm.len() - 9 ..], [0, 0, 0, 255, 255, 255, 255, 255, 255]);
+
+        let mut video = Y4mWriter::new(Vec::new(), 3, 2, 25).unwrap();
+        video.write_frame(&image).unwrap();
+        video.write_frame(&image).unwrap();
+        let video = video.into_inner();
+        let header = b"YUV4MPEG2 W3 H2 F25:1 Ip A1:1 C444\n";
+        assert!(video.starts_with(header));
+        assert_eq!(video.len(), header.len() + 2 * (6 + 3 * 6));
+        /* luma of black and white, neutral chroma for both */
+        let frame = &video[header.len() + 6 ..];
+        assert_eq!(frame[1 .. 6], [16, 16, 16, 235, 235]);
+        assert_eq!(frame[9 .. 12], [128, 128, 128]);
+        assert_eq!(frame[15 .. 18], [128, 128, 128]);
+    }
+}
@@ -0,0 +1,179 @@
+/// Half-open interval `start .. end` of integers, empty if `end <= start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Interval {
+        Interval { start, end }
+    }
+
+    /// Interval `first ..= last`, including both ends.
+    pub fn closed(first: i64, last: i64) -> Interval {
+        Interval { start: first, end: last + 1 }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn len(&self) -> u64 {
+        if self.is_empty() { 0 } else { self.start.abs_diff(self.end) }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether every value of `other` is also in this interval.
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Interval {
+        Interval { start: self.start.max(other.start), end: self.end.min(other.end) }
+    }
+}
+
+/// Set of integers, kept as sorted and disjoint intervals.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RangeSet {
+    intervals: Vec<Interval>,
+}
+
+impl RangeSet {
+    pub fn new() -> RangeSet {
+        RangeSet::default()
+    }
+
+    /* sorts the intervals and merges the overlapping or touching ones */
+    fn merge(mut intervals: Vec<Interval>) -> RangeSet {
+        intervals.retain(|i| !i.is_empty());
+        intervals.sort_by_key(|i| i.start);
+        let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        RangeSet { intervals: merged }
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.push(interval);
+        *self = RangeSet::merge(intervals);
+    }
+
+    /// The disjoint intervals of the set, in ascending order.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let idx = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(idx).is_some_and(|i| i.contains(value))
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        RangeSet::merge(self.intervals.iter().chain(&other.intervals).copied().collect())
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut intervals = Vec::new();
+        let (mut a, mut b) = (0, 0);
+        while a < self.intervals.len() && b < other.intervals.len() {
+            let (left, right) = (self.intervals[a], other.intervals[b]);
+            let common = left.intersection(&right);
+            if !common.is_empty() {
+                intervals.push(common);
+            }
+            /* continue with whichever interval ends first */
+            if left.end < right.end { a += 1; } else { b += 1; }
+        }
+        RangeSet { intervals }
+    }
+
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut intervals = Vec::new();
+        let mut b = 0;
+        for &interval in &self.intervals {
+            let mut start = interval.start;
+            while b < other.intervals.len() && other.intervals[b].end <= start {
+                b += 1;
+            }
+            let mut next = b;
+            while next < other.intervals.len() && other.intervals[next].start < interval.end {
+                let removed = other.intervals[next];
+                if removed.start > start {
+                    intervals.push(Interval::new(start, removed.start));
+                }
+                start = start.max(removed.end);
+                next += 1;
+            }
+            if start < interval.end {
+                intervals.push(Interval::new(start, interval.end));
+            }
+        }
+        RangeSet { intervals }
+    }
+
+    /// Holes between the intervals of the set.
+    pub fn gaps(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.intervals.windows(2).map(|w| Interval::new(w[0].end, w[1].start))
+    }
+}
+
+impl FromIterator<Interval> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> RangeSet {
+        RangeSet::merge(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let interval = Interval::closed(2, 4);
+        assert_eq!(interval, Interval::new(2, 5));
+        assert_eq!(interval.len(), 3);
+        assert!(interval.contains(4) && !interval.contains(5));
+        assert!(interval.contains_interval(&Interval::closed(3, 4)));
+        assert!(!interval.overlaps(&Interval::new(5, 8)));
+        assert_eq!(interval.intersection(&Interval::new(4, 8)).len(), 1);
+        assert_eq!(Interval::new(3, 1).len(), 0);
+
+        let a = [Interval::new(5, 8), Interval::new(0, 3), Interval::new(2, 4), Interval::new(8, 9)].into_iter().collect::<RangeSet>();
+        assert_eq!(a.intervals(), [Interval::new(0, 4), Interval::new(5, 9)]);
+        assert_eq!(a.len(), 8);
+        assert!(a.contains(0) && a.contains(8) && !a.contains(4) && !a.contains(9));
+        assert_eq!(a.gaps().collect::<Vec<_>>(), [Interval::new(4, 5)]);
+
+        let mut b = RangeSet::new();
+        b.insert(Interval::new(3, 6));
+        b.insert(Interval::new(-2, 1));
+        assert_eq!(a.union(&b).intervals(), [Interval::new(-2, 9)]);
+        assert_eq!(a.intersection(&b).intervals(), [Interval::new(0, 1), Interval::new(3, 4), Interval::new(5, 6)]);
+        assert_eq!(a.difference(&b).intervals(), [Interval::new(1, 3), Interval::new(6, 9)]);
+        assert_eq!(b.difference(&a).intervals(), [Interval::new(-2, 0), Interval::new(4, 5)]);
+        assert!(a.difference(&a).is_empty());
+    }
+}
@@ -1,19 +1,305 @@
 use std::str::FromStr;
-use std::fmt::Debug;
+use std::ffi::OsString;
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+pub mod answers;
+pub mod bench;
+pub mod cycle;
+pub mod days;
+pub mod geom;
+pub mod grid;
+pub mod image;
+pub mod interval;
+pub mod search;
+mod solution;
+
+pub use solution::{Answer, DynSolution, Solution};
+
+/// Environment variable naming the directory that holds the `dayN` inputs.
+pub const INPUTS_ENV: &str = "ADVENT_INPUTS";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn in_dir(dir: impl AsRef<Path>, day: u8) -> InputSource {
+        InputSource::File(dir.as_ref().join(format!("day{}", day)))
+    }
+
+    /// Input of a day binary, taken from its command line and environment.
+    pub fn for_day(day: u8) -> InputSource {
+        InputSource::from_args(day, std::env::args().skip(1), std::env::var_os(INPUTS_ENV))
+    }
+
+    /// Resolves the input from `--input FILE` (`-` for stdin), `--inputs DIR`
+    /// or the inputs directory from the environment, in that order.
+    /// Falls back to `inputs/dayN`; unrelated arguments are ignored.
+    pub fn from_args(day: u8, args: impl IntoIterator<Item = String>, inputs_env: Option<OsString>) -> InputSource {
+        let args = args.into_iter().collect::<Vec<_>>();
+        match (option(&args, "--input"), option(&args, "--inputs")) {
+            (Some(file), _) if file == "-" => InputSource::Stdin,
+            (Some(file), _) => InputSource::File(PathBuf::from(file)),
+            (None, Some(dir)) => InputSource::in_dir(dir, day),
+            (None, None) => InputSource::in_dir(inputs_env.unwrap_or_else(|| OsString::from("inputs")), day),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, Error> {
+        let result = match self {
+            InputSource::File(path) => std::fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut buffer = String::new();
+                io::stdin().read_to_string(&mut buffer).map(|_| buffer)
+            },
+        };
+        result.map_err(|source| Error::Io { input: self.clone(), source })
+    }
+}
+
+/// Value of the command line option `name`, given as `name VALUE` or `name=VALUE`;
+/// the last one counts.
+pub fn option(args: &[String], name: &str) -> Option<String> {
+    let mut value = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == name {
+            value = args.next().cloned();
+        } else if let Some(rest) = arg.strip_prefix(name).and_then(|rest| rest.strip_prefix('=')) {
+            value = Some(rest.to_string());
+        }
+    }
+    value
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// The input could not be read.
+    Io { input: InputSource, source: io::Error },
+    /// A line of the input could not be parsed; `line` is 1-based.
+    Parse { input: Option<InputSource>, line: usize, text: String, source: Box<dyn std::error::Error + Send + Sync> },
+    /// The input can't be solved as a whole, e.g. because its parts contradict each other.
+    Invalid { input: Option<InputSource>, source: Box<dyn std::error::Error + Send + Sync> },
+}
+
+impl Error {
+    /// Error on line `line` (1-based) with content `text`, from a day's `parse`
+    /// that doesn't know where its input came from.
+    pub fn parse(line: usize, text: &str, source: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Error {
+        Error::Parse { input: None, line, text: text.to_string(), source: source.into() }
+    }
+
+    pub fn invalid(source: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Error {
+        Error::Invalid { input: None, source: source.into() }
+    }
+
+    /// Attributes the error to `source`, unless it already names its input.
+    pub fn in_input(self, source: &InputSource) -> Error {
+        match self {
+            Error::Parse { input: None, line, text, source: err } => Error::Parse { input: Some(source.clone()), line, text, source: err },
+            Error::Invalid { input: None, source: err } => Error::Invalid { input: Some(source.clone()), source: err },
+            err => err,
+        }
+    }
+
+    /// Whether the input doesn't exist, as opposed to being unreadable or invalid.
+    pub fn is_missing_input(&self) -> bool {
+        matches!(self, Error::Io { source, .. } if source.kind() == io::ErrorKind::NotFound)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io { input, source } => write!(f, "{}: {}", input, source),
+            Error::Parse { input: Some(input), line, text, source } => write!(f, "{}:{}: {} ({:?})", input, line, source, text),
+            Error::Parse { input: None, line, text, source } => write!(f, "line {}: {} ({:?})", line, source, text),
+            Error::Invalid { input: Some(input), source } => write!(f, "{}: {}", input, source),
+            Error::Invalid { input: None, source } => write!(f, "{}", source),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse { source, .. } | Error::Invalid { source, .. } => Some(source.as_ref()),
+        }
+    }
+}
+
+/// Reports the error and terminates, instead of panicking with a backtrace.
+pub fn exit_with(err: impl fmt::Display) -> ! {
+    eprintln!("error: {}", err);
+    std::process::exit(1);
+}
+
+pub fn try_read_file(day: u8) -> Result<String, Error> {
+    InputSource::for_day(day).read()
+}
+
+pub fn lines(input: &str) -> Vec<String> {
+    input.split_terminator('\n')
+         .map(String::from)
+         .collect()
+}
+
+pub fn try_read_lines(day: u8) -> Result<Vec<String>, Error> {
+    Ok(lines(&try_read_file(day)?))
+}
+
+/// Splits lines into groups separated by blank lines.
+/// Carriage returns are dropped and repeated, leading or trailing blank lines don't create empty groups.
+pub fn groups(lines: &[String]) -> Vec<Vec<String>> {
+    let mut groups = Vec::new();
+    let mut group = Vec::new();
+    for line in lines {
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() {
+            if !group.is_empty() {
+                groups.push(std::mem::take(&mut group));
+            }
+            continue;
+        }
+        group.push(line.to_string());
+    }
+    if !group.is_empty() {
+        groups.push(group);
+    }
+    groups
+}
+
+pub fn try_read_groups(day: u8) -> Result<Vec<Vec<String>>, Error> {
+    Ok(groups(&try_read_lines(day)?))
+}
+
+pub fn try_read_numbers<T: FromStr>(day: u8) -> Result<Vec<T>, Error>
+    where <T as FromStr>::Err: std::error::Error + Send + Sync + 'static
+{
+    numbers_from(&InputSource::for_day(day))
+}
+
+/* one number per line of the input */
+fn numbers_from<T: FromStr>(input: &InputSource) -> Result<Vec<T>, Error>
+    where <T as FromStr>::Err: std::error::Error + Send + Sync + 'static
+{
+    input.read()?.split_terminator('\n')
+                 .enumerate()
+                 .map(|(idx, n)| n.parse::<T>().map_err(|err| Error::parse(idx + 1, n, err).in_input(input)))
+                 .collect()
+}
 
 pub fn read_file(day: u8) -> String {
-    let filename = format!("inputs/day{}", day);
-    std::fs::read_to_string(filename).unwrap()
+    try_read_file(day).unwrap_or_else(|err| exit_with(err))
 }
 
 pub fn read_lines(day: u8) -> Vec<String> {
-    read_file(day).split_terminator('\n')
-                  .map(String::from)
-                  .collect()
+    try_read_lines(day).unwrap_or_else(|err| exit_with(err))
+}
+
+pub fn read_groups(day: u8) -> Vec<Vec<String>> {
+    try_read_groups(day).unwrap_or_else(|err| exit_with(err))
+}
+
+pub fn read_numbers<T: FromStr>(day: u8) -> Vec<T>
+    where <T as FromStr>::Err: std::error::Error + Send + Sync + 'static
+{
+    try_read_numbers(day).unwrap_or_else(|err| exit_with(err))
+}
+
+/// Reads and parses the input of a day binary, exiting if either fails.
+pub fn parse_input<S: Solution>(day: u8, solution: &S) -> S::Input {
+    let source = InputSource::for_day(day);
+    let input = source.read().unwrap_or_else(|err| exit_with(err));
+    solution.parse(&input).unwrap_or_else(|err| exit_with(err.in_input(&source)))
+}
+
+/// Solves all parts of a day on its input and prints the answers.
+pub fn run<S: Solution>(day: u8, solution: &S) {
+    let input = parse_input(day, solution);
+    for part in 0 .. S::PARTS {
+        let answer = if part == 0 { solution.part1(&input) } else { solution.part2(&input) };
+        let answer = answer.unwrap_or_else(|err| exit_with(err.in_input(&InputSource::for_day(day))));
+        let name = (b'a' + part as u8) as char;
+        match answer {
+            Answer::Art(art) => println!("{day}{name}: \n{art}"),
+            answer => println!("{day}{name}: {answer}"),
+        }
+    }
 }
 
-pub fn read_numbers<T: FromStr>(day: u8) -> Vec<T> where <T as FromStr>::Err: Debug {
-    read_lines(day).iter()
-                   .map(|n| n.parse::<T>().unwrap())
-                   .collect::<Vec<T>>()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors() {
+        let missing = InputSource::in_dir("inputs", 0);
+        match missing.read() {
+            Err(Error::Io { input, .. }) => assert_eq!(input, InputSource::File(PathBuf::from("inputs/day0"))),
+            _ => panic!("day 0 should not have an input"),
+        }
+        assert!(missing.read().unwrap_err().is_missing_input());
+
+        /* day 13 contains packets, which do not parse as numbers */
+        let err = numbers_from::<u32>(&InputSource::in_dir("inputs", 13)).unwrap_err();
+        match &err {
+            Error::Parse { input, line, text, .. } => {
+                assert_eq!(input, &Some(InputSource::File(PathBuf::from("inputs/day13"))));
+                assert_eq!(*line, 1);
+                assert!(text.starts_with('['));
+            },
+            _ => panic!("expected parse error"),
+        }
+        assert!(err.to_string().starts_with("inputs/day13:1: invalid digit"));
+
+        /* errors of a day's parse get their input from the runner */
+        let err = Error::parse(3, "x", "not a number");
+        assert_eq!(err.to_string(), "line 3: not a number (\"x\")");
+        assert_eq!(err.in_input(&InputSource::Stdin).to_string(), "<stdin>:3: not a number (\"x\")");
+        let err = Error::invalid("no solution").in_input(&InputSource::in_dir("inputs", 5));
+        assert_eq!(err.in_input(&InputSource::Stdin).to_string(), "inputs/day5: no solution");
+    }
+
+    #[test]
+    fn test_groups() {
+        let input = lines("\r\n1\r\n2\r\n\r\n\r\n3\n  \n4\n\n\n");
+        assert_eq!(groups(&input), [vec!["1", "2"], vec!["3"], vec!["4"]]);
+        assert_eq!(groups(&lines("1\n2")), [vec!["1", "2"]]);
+        assert!(groups(&lines("\n\n")).is_empty());
+    }
+
+    #[test]
+    fn test_input_source() {
+        let args = |list: &[&str]| list.iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+
+        assert_eq!(InputSource::from_args(3, args(&[]), None),
+                   InputSource::File(PathBuf::from("inputs/day3")));
+        assert_eq!(InputSource::from_args(3, args(&[]), Some(OsString::from("other"))),
+                   InputSource::File(PathBuf::from("other/day3")));
+        assert_eq!(InputSource::from_args(3, args(&["--inputs", "cli"]), Some(OsString::from("other"))),
+                   InputSource::File(PathBuf::from("cli/day3")));
+        assert_eq!(InputSource::from_args(3, args(&["--inputs=cli", "--input", "x.txt"]), None),
+                   InputSource::File(PathBuf::from("x.txt")));
+        assert_eq!(InputSource::from_args(3, args(&["--verbose", "--input=-"]), None),
+                   InputSource::Stdin);
+
+        assert_eq!(option(&args(&["--seed", "7", "--game"]), "--seed"), Some("7".to_string()));
+        assert_eq!(option(&args(&["--seed=1", "--seeds", "2", "--seed", "3"]), "--seed"), Some("3".to_string()));
+        assert_eq!(option(&args(&["--seeds=2", "--seed"]), "--seed"), None);
+    }
 }
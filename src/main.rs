@@ -0,0 +1,472 @@
+use std::fmt;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+use advent::answers::{Answers, Verdict};
+use advent::bench::{self, Step};
+use advent::days::{self, Day};
+use std::path::PathBuf;
+use advent::{InputSource, INPUTS_ENV};
+
+static USAGE: &str = "\
+usage: advent [list | verify [--update] | bench [--runs N]] [DAYS...] [--part a|b]
+              [--input FILE | --inputs DIR] [--answers DIR]
+              [--output FILE] [--baseline FILE] [--threshold PERCENT]
+
+DAYS can be single days (5), ranges (3-7) or \"all\" (default).
+--input reads the input of a single day from FILE (- for stdin),
+--inputs reads dayN files from DIR instead of inputs/.
+verify compares the answers with the known ones in answers/ (or
+--answers DIR); --update stores answers that are not known yet.
+bench times parsing and each part N times (default 5) and reports
+min/median/max; --output writes the timings as JSON, --baseline
+compares against such a file and flags medians slower by more than
+--threshold percent (default 10).";
+
+#[derive(Debug, PartialEq, Eq)]
+enum Mode {
+    Run,
+    List,
+    Verify { update: bool },
+    Bench,
+}
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    mode: Mode,
+    days: Vec<u8>,
+    parts: Vec<usize>,
+    answers: Option<String>,
+    runs: usize,
+    output: Option<String>,
+    baseline: Option<String>,
+    threshold: f64,
+    /* --input FILE for a single day and --inputs DIR */
+    input: Option<InputSource>,
+    inputs: Option<PathBuf>,
+    /* whether a missing input is an error rather than a day to skip */
+    require_input: bool,
+}
+
+impl Options {
+    /* where the input of a day comes from, the environment's directory being the last resort */
+    fn source(&self, day: u8) -> InputSource {
+        match (&self.input, &self.inputs) {
+            (Some(input), _) => input.clone(),
+            (None, Some(dir)) => InputSource::in_dir(dir, day),
+            (None, None) => InputSource::from_args(day, [], std::env::var_os(INPUTS_ENV)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Status {
+    Done(String),
+    /// The day has no input, e.g. because the puzzle wasn't downloaded yet.
+    Skipped(String),
+    Failed(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Done(answer) => write!(f, "{}", answer),
+            Status::Skipped(reason) => write!(f, "skipped: {}", reason),
+            Status::Failed(err) => write!(f, "error: {}", err),
+        }
+    }
+}
+
+struct Row {
+    day: u8,
+    step: Step,
+    time: Duration,
+    status: Status,
+}
+
+fn parse_days(spec: &str) -> Result<Vec<u8>, String> {
+    if spec == "all" {
+        return Ok(days::DAYS.iter().map(|d| d.day).collect());
+    }
+    let parse = |day: &str| day.parse::<u8>().map_err(|_| format!("invalid day: {}", spec));
+    let (first, last) = match spec.split_once('-') {
+        Some((first, last)) => (parse(first)?, parse(last)?),
+        None => (parse(spec)?, parse(spec)?),
+    };
+    let selected = days::DAYS.iter()
+                             .map(|d| d.day)
+                             .filter(|day| (first ..= last).contains(day))
+                             .collect::<Vec<_>>();
+    if selected.is_empty() {
+        return Err(format!("no such day: {}", spec));
+    }
+    Ok(selected)
+}
+
+/* a file, or stdin for - */
+fn input_source(file: &str) -> InputSource {
+    if file == "-" { InputSource::Stdin } else { InputSource::File(PathBuf::from(file)) }
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        mode: Mode::Run,
+        days: Vec::new(),
+        parts: vec![0, 1],
+        answers: None,
+        runs: 5,
+        output: None,
+        baseline: None,
+        threshold: 10.0,
+        input: None,
+        inputs: None,
+        require_input: false,
+    };
+    let mut update = false;
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "list" => options.mode = Mode::List,
+            "verify" => options.mode = Mode::Verify { update: false },
+            "bench" => options.mode = Mode::Bench,
+            "--update" => update = true,
+            "--runs" => {
+                options.runs = it.next().and_then(|n| n.parse().ok())
+                                        .filter(|&n| n > 0)
+                                        .ok_or("--runs needs a positive number")?;
+            },
+            "--threshold" => {
+                options.threshold = it.next().and_then(|n| n.parse().ok())
+                                             .ok_or("--threshold needs a number")?;
+            },
+            "--output" => {
+                options.output = Some(it.next().ok_or("--output needs a file")?.clone());
+            },
+            "--baseline" => {
+                options.baseline = Some(it.next().ok_or("--baseline needs a file")?.clone());
+            },
+            "--answers" => {
+                options.answers = Some(it.next().ok_or("--answers needs a directory")?.clone());
+            },
+            "--part" => {
+                options.parts = match it.next().map(String::as_str) {
+                    Some("a") => vec![0],
+                    Some("b") => vec![1],
+                    _ => return Err("--part needs a or b".to_string()),
+                };
+            },
+            "--input" => options.input = Some(input_source(it.next().ok_or("--input needs a file")?)),
+            "--inputs" => options.inputs = Some(PathBuf::from(it.next().ok_or("--inputs needs a directory")?)),
+            _ if arg.starts_with("--input=") => options.input = Some(input_source(&arg["--input=".len() ..])),
+            _ if arg.starts_with("--inputs=") => options.inputs = Some(PathBuf::from(&arg["--inputs=".len() ..])),
+            _ if arg.starts_with('-') => return Err(format!("unknown option: {}", arg)),
+            spec => {
+                for day in parse_days(spec)? {
+                    if !options.days.contains(&day) {
+                        options.days.push(day);
+                    }
+                }
+            },
+        }
+    }
+    if options.days.is_empty() {
+        options.days = parse_days("all")?;
+    }
+    if options.input.is_some() && options.days.len() != 1 {
+        return Err("--input can only be used with a single day".to_string());
+    }
+    options.require_input = options.input.is_some();
+    if update {
+        match options.mode {
+            Mode::Verify { .. } => options.mode = Mode::Verify { update },
+            _ => return Err("--update can only be used with verify".to_string()),
+        }
+    }
+    Ok(options)
+}
+
+/* reads the input of a day, or tells why there is none */
+fn read_input(day: u8, options: &Options) -> Result<String, Status> {
+    let source = options.source(day);
+    source.read().map_err(|err| {
+        if err.is_missing_input() && !options.require_input {
+            Status::Skipped(format!("no input ({})", source))
+        } else {
+            Status::Failed(err.to_string())
+        }
+    })
+}
+
+fn run_day(day: &Day, options: &Options) -> Vec<Row> {
+    let input = match read_input(day.day, options) {
+        Ok(input) => input,
+        Err(status) => return vec![Row { day: day.day, step: Step::Parse, time: Duration::ZERO, status }],
+    };
+
+    /* errors name the input they came from */
+    let failed = |err: advent::Error| Status::Failed(err.in_input(&options.source(day.day)).to_string());
+
+    /* the input is parsed once and shared by all parts */
+    let start = Instant::now();
+    let parsed = day.solution.parse(&input);
+    let time = start.elapsed();
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(err) => return vec![Row { day: day.day, step: Step::Parse, time, status: failed(err) }],
+    };
+    let mut rows = vec![Row { day: day.day, step: Step::Parse, time, status: Status::Done(String::new()) }];
+
+    for &part in options.parts.iter().filter(|&&part| part < day.solution.parts()) {
+        let start = Instant::now();
+        let answer = day.solution.solve(parsed.as_ref(), part);
+        let time = start.elapsed();
+        let status = match answer {
+            Ok(answer) => Status::Done(answer.to_string()),
+            Err(err) => failed(err),
+        };
+        rows.push(Row { day: day.day, step: Step::Part(part), time, status });
+    }
+    rows
+}
+
+/* prints the first line after the prefix, and aligns further lines below it */
+fn print_lines(prefix: &str, text: &str) {
+    let mut lines = text.trim_end().lines();
+    let line = format!("{}{}", prefix, lines.next().unwrap_or(""));
+    println!("{}", line.trim_end());
+    for line in lines {
+        println!("{:width$}{}", "", line, width = prefix.chars().count());
+    }
+}
+
+fn print_table(rows: &[Row]) {
+    println!("{:>3}  {:5}  {:>12}  answer", "day", "part", "time");
+    for row in rows {
+        print_lines(&format!("{:>3}  {:>5}  {:>12}  ", row.day, row.step, format!("{:.2?}", row.time)), &row.status.to_string());
+    }
+    let total : Duration = rows.iter().map(|r| r.time).sum();
+    println!("{:>3}  {:5}  {:>12}", "", "", format!("{:.2?}", total));
+}
+
+/* returns false if the benchmark could not run or got slower */
+fn benchmark(options: &Options) -> bool {
+    let baseline = match &options.baseline {
+        Some(file) => match std::fs::read_to_string(file).map_err(|e| e.to_string())
+                                                           .and_then(|json| bench::from_json(&json)) {
+            Ok(baseline) => Some(baseline),
+            Err(err) => {
+                eprintln!("error: {}: {}", file, err);
+                return false;
+            },
+        },
+        None => None,
+    };
+
+    println!("{:>3}  {:5}  {:>12}  {:>12}  {:>12}  {:>9}", "day", "step", "min", "median", "max", "baseline");
+    let mut success = true;
+    let mut timings = Vec::new();
+    for day in options.days.iter().filter_map(|&d| days::find(d)) {
+        let input = match read_input(day.day, options) {
+            Ok(input) => input,
+            Err(status) => {
+                println!("{:>3}  {}", day.day, status);
+                success &= matches!(status, Status::Skipped(_));
+                continue;
+            },
+        };
+        let timings_of_day = match bench::bench_day(day, &input, options.runs) {
+            Ok(timings) => timings,
+            Err(err) => {
+                println!("{:>3}  error: {}", day.day, err.in_input(&options.source(day.day)));
+                success = false;
+                continue;
+            },
+        };
+        for timing in timings_of_day {
+            let change = baseline.as_ref()
+                                 .and_then(|b| b.iter().find(|b| b.day == timing.day && b.step == timing.step))
+                                 .map(|b| format!("{:+.1}%", (timing.median.as_secs_f64() / b.median.as_secs_f64() - 1.0) * 100.0))
+                                 .unwrap_or_default();
+            let line = format!("{:>3}  {:>5}  {:>12}  {:>12}  {:>12}  {:>9}", timing.day, timing.step,
+                               format!("{:.2?}", timing.min), format!("{:.2?}", timing.median), format!("{:.2?}", timing.max), change);
+            println!("{}", line.trim_end());
+            timings.push(timing);
+        }
+    }
+
+    if let Some(file) = &options.output {
+        if let Err(err) = std::fs::write(file, bench::to_json(&timings, options.runs)) {
+            eprintln!("error: {}: {}", file, err);
+            success = false;
+        }
+    }
+
+    if let Some(baseline) = baseline {
+        let regressions = bench::regressions(&timings, &baseline, options.threshold);
+        for r in &regressions {
+            println!("regression: day {} {}: {:.2?} -> {:.2?} ({:+.1}%)", r.day, r.step, r.baseline, r.current, r.change);
+        }
+        success &= regressions.is_empty();
+    }
+    success
+}
+
+/* returns whether all computed answers matched the known ones */
+fn verify(rows: &[Row], answers: &Answers, update: bool) -> bool {
+    let (mut correct, mut wrong, mut missing, mut errors, mut skipped) = (0, 0, 0, 0, 0);
+    for row in rows {
+        let prefix = format!("{:>3}  {:>5}  ", row.day, row.step);
+        let (answer, part) = match (&row.status, row.step) {
+            (Status::Failed(_), _) => {
+                errors += 1;
+                print_lines(&prefix, &row.status.to_string());
+                continue;
+            },
+            (Status::Skipped(_), _) => {
+                skipped += 1;
+                print_lines(&prefix, &row.status.to_string());
+                continue;
+            },
+            (Status::Done(_), Step::Parse) => continue,
+            (Status::Done(answer), Step::Part(part)) => (answer, part),
+        };
+        match answers.verify(row.day, part, answer) {
+            Ok(Verdict::Correct) => {
+                correct += 1;
+                println!("{}ok", prefix);
+            },
+            Ok(Verdict::Mismatch { expected }) => {
+                wrong += 1;
+                print_lines(&format!("{}MISMATCH expected: ", prefix), &expected);
+                print_lines(&format!("{}{:>19}", prefix, "got: "), answer);
+            },
+            Ok(Verdict::Missing) => {
+                missing += 1;
+                let stored = if update {
+                    match answers.store(row.day, part, answer) {
+                        Ok(()) => " (stored)",
+                        Err(err) => {
+                            errors += 1;
+                            print_lines(&prefix, &format!("error: {}", err));
+                            " (not stored)"
+                        },
+                    }
+                } else {
+                    ""
+                };
+                print_lines(&format!("{}new{}: ", prefix, stored), answer);
+            },
+            Err(err) => {
+                errors += 1;
+                print_lines(&prefix, &format!("error: {}", err));
+            },
+        }
+    }
+    println!("{} correct, {} wrong, {} new, {} errors, {} skipped", correct, wrong, missing, errors, skipped);
+    wrong == 0 && errors == 0
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.iter().any(|a| a == "--help" || a == "-h") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("error: {}\n{}", err, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if options.mode == Mode::List {
+        for day in options.days.iter().filter_map(|&d| days::find(d)) {
+            println!("{:>2}: {} part(s)", day.day, day.solution.parts());
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    if options.mode == Mode::Bench {
+        return if benchmark(&options) { ExitCode::SUCCESS } else { ExitCode::FAILURE };
+    }
+
+    let rows = options.days.iter()
+                           .filter_map(|&d| days::find(d))
+                           .flat_map(|day| run_day(day, &options))
+                           .collect::<Vec<_>>();
+
+    let success = match options.mode {
+        Mode::Verify { update } => {
+            let answers = match &options.answers {
+                Some(dir) => Answers::new(dir),
+                None => Answers::from_env(),
+            };
+            verify(&rows, &answers, update)
+        },
+        _ => {
+            print_table(&rows);
+            rows.iter().all(|r| !matches!(r.status, Status::Failed(_)))
+        },
+    };
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let args = |list: &[&str]| list.iter().map(|&x| String::from(x)).collect::<Vec<_>>();
+
+        assert_eq!(parse_days("5"), Ok(vec![5]));
+        assert_eq!(parse_days("17-21"), Ok(vec![17, 18, 19, 20, 21]));
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("x").is_err());
+        assert_eq!(parse_days("all").unwrap().len(), days::DAYS.len());
+
+        let options = parse_args(&args(&["3", "1-2", "--part", "b", "--inputs", "other"])).unwrap();
+        assert_eq!(options.mode, Mode::Run);
+        assert_eq!(options.days, vec![3, 1, 2]);
+        assert_eq!(options.parts, vec![1]);
+        assert_eq!(parse_args(&args(&["list"])).unwrap().days.len(), days::DAYS.len());
+        let options = parse_args(&args(&["verify", "--update", "--answers", "known"])).unwrap();
+        assert_eq!(options.mode, Mode::Verify { update: true });
+        assert_eq!(options.answers, Some("known".to_string()));
+        assert!(parse_args(&args(&["--update"])).is_err());
+        let options = parse_args(&args(&["bench", "16", "--runs", "3", "--baseline", "old.json", "--threshold", "25"])).unwrap();
+        assert_eq!(options.mode, Mode::Bench);
+        assert_eq!((options.runs, options.threshold), (3, 25.0));
+        assert_eq!(options.baseline, Some("old.json".to_string()));
+        assert!(parse_args(&args(&["bench", "--runs", "0"])).is_err());
+        assert!(parse_args(&args(&["--input", "x"])).is_err());
+        assert!(parse_args(&args(&["4", "--input", "x"])).unwrap().require_input);
+        assert!(!parse_args(&args(&["4", "--inputs", "x"])).unwrap().require_input);
+
+        assert_eq!(parse_args(&args(&["4", "--input=-"])).unwrap().source(4), InputSource::Stdin);
+        assert_eq!(parse_args(&args(&["--inputs=other"])).unwrap().source(4), InputSource::File(PathBuf::from("other/day4")));
+
+        /* a day without input is skipped, unless its input was given explicitly */
+        let options = parse_args(&args(&["1", "--inputs", "no-such-dir"])).unwrap();
+        assert_eq!(read_input(1, &options), Err(Status::Skipped("no input (no-such-dir/day1)".to_string())));
+        let options = Options { require_input: true, ..options };
+        assert!(matches!(read_input(1, &options), Err(Status::Failed(_))));
+
+        /* a day with a bad input fails on its own */
+        let dir = std::env::temp_dir().join(format!("advent-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("day1"), "1\nx\n").unwrap();
+        std::fs::write(dir.join("day2"), "A X\n").unwrap();
+        let options = Options { days: vec![1, 2], inputs: Some(dir.clone()), ..parse_args(&[]).unwrap() };
+        let rows = options.days.iter().flat_map(|&d| run_day(days::find(d).unwrap(), &options)).collect::<Vec<_>>();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(rows[0].status, Status::Failed(format!("{}:2: invalid digit found in string (\"x\")", dir.join("day1").display())));
+        assert_eq!(rows.iter().map(|r| r.status.to_string()).skip(1).collect::<Vec<_>>(), ["", "4", "3"]);
+        assert!(parse_args(&args(&["--verbose"])).is_err());
+    }
+}
@@ -0,0 +1,202 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A goal reached by one of the searches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub goal: N,
+    pub cost: C,
+    /// Nodes from the start up to and including the goal.
+    pub nodes: Vec<N>,
+}
+
+/* best known cost and predecessor of every discovered node */
+type Visited<N, C> = HashMap<N, (C, Option<N>)>;
+
+fn reconstruct<N: Eq + Hash + Clone, C: Copy>(visited: &Visited<N, C>, goal: N) -> Path<N, C> {
+    let cost = visited[&goal].0;
+    let mut nodes = vec![goal.clone()];
+    while let Some(previous) = &visited[nodes.last().unwrap()].1 {
+        nodes.push(previous.clone());
+    }
+    nodes.reverse();
+    Path { goal, cost, nodes }
+}
+
+fn bfs_visit<N, I>(starts: impl IntoIterator<Item = N>,
+                   mut neighbors: impl FnMut(&N) -> I,
+                   mut is_goal: impl FnMut(&N) -> bool) -> (Visited<N, usize>, Option<N>)
+    where N: Eq + Hash + Clone, I: IntoIterator<Item = N>
+{
+    let mut visited = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !visited.contains_key(&start) {
+            visited.insert(start.clone(), (0, None));
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return (visited, Some(node));
+        }
+        let cost = visited[&node].0 + 1;
+        for next in neighbors(&node) {
+            if !visited.contains_key(&next) {
+                visited.insert(next.clone(), (cost, Some(node.clone())));
+                queue.push_back(next);
+            }
+        }
+    }
+    (visited, None)
+}
+
+/// Breadth-first search where every step costs 1.
+/// Returns `None` if no goal can be reached from any of the starts.
+pub fn bfs<N, I>(starts: impl IntoIterator<Item = N>,
+                 neighbors: impl FnMut(&N) -> I,
+                 is_goal: impl FnMut(&N) -> bool) -> Option<Path<N, usize>>
+    where N: Eq + Hash + Clone, I: IntoIterator<Item = N>
+{
+    let (visited, goal) = bfs_visit(starts, neighbors, is_goal);
+    Some(reconstruct(&visited, goal?))
+}
+
+/// Number of steps to every node reachable from the starts.
+pub fn bfs_distances<N, I>(starts: impl IntoIterator<Item = N>, neighbors: impl FnMut(&N) -> I) -> HashMap<N, usize>
+    where N: Eq + Hash + Clone, I: IntoIterator<Item = N>
+{
+    let (visited, _) = bfs_visit(starts, neighbors, |_| false);
+    visited.into_iter().map(|(node, (cost, _))| (node, cost)).collect()
+}
+
+struct State<N, C> {
+    priority: C,
+    node: N,
+}
+
+/* comparator for priority queue, lowest priority first */
+impl<N, C: Ord> Ord for State<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for State<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for State<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for State<N, C> {}
+
+fn astar_visit<N, C, I>(starts: impl IntoIterator<Item = N>,
+                        mut neighbors: impl FnMut(&N) -> I,
+                        mut heuristic: impl FnMut(&N) -> C,
+                        mut is_goal: impl FnMut(&N) -> bool) -> (Visited<N, C>, Option<N>)
+    where N: Eq + Hash + Clone, C: Ord + Copy + Default + Add<Output = C>, I: IntoIterator<Item = (N, C)>
+{
+    let mut visited: Visited<N, C> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        visited.insert(start.clone(), (C::default(), None));
+        heap.push(State { priority: heuristic(&start), node: start });
+    }
+
+    while let Some(State { priority, node }) = heap.pop() {
+        let cost = visited[&node].0;
+        if priority > cost + heuristic(&node) {
+            /* outdated entry, the node was reached cheaper in the meantime */
+            continue;
+        }
+        if is_goal(&node) {
+            return (visited, Some(node));
+        }
+        for (next, step) in neighbors(&node) {
+            let next_cost = cost + step;
+            if visited.get(&next).is_some_and(|&(known, _)| known <= next_cost) {
+                continue;
+            }
+            visited.insert(next.clone(), (next_cost, Some(node.clone())));
+            heap.push(State { priority: next_cost + heuristic(&next), node: next });
+        }
+    }
+    (visited, None)
+}
+
+/// Cheapest path to a goal, with non-negative step costs given by `neighbors`.
+pub fn dijkstra<N, C, I>(starts: impl IntoIterator<Item = N>,
+                         neighbors: impl FnMut(&N) -> I,
+                         is_goal: impl FnMut(&N) -> bool) -> Option<Path<N, C>>
+    where N: Eq + Hash + Clone, C: Ord + Copy + Default + Add<Output = C>, I: IntoIterator<Item = (N, C)>
+{
+    astar(starts, neighbors, |_| C::default(), is_goal)
+}
+
+/// Cost of the cheapest path to every node reachable from the starts.
+pub fn dijkstra_distances<N, C, I>(starts: impl IntoIterator<Item = N>, neighbors: impl FnMut(&N) -> I) -> HashMap<N, C>
+    where N: Eq + Hash + Clone, C: Ord + Copy + Default + Add<Output = C>, I: IntoIterator<Item = (N, C)>
+{
+    let (visited, _) = astar_visit(starts, neighbors, |_| C::default(), |_| false);
+    visited.into_iter().map(|(node, (cost, _))| (node, cost)).collect()
+}
+
+/// Like [`dijkstra`], guided by a `heuristic` that must never overestimate
+/// the remaining cost to a goal.
+pub fn astar<N, C, I>(starts: impl IntoIterator<Item = N>,
+                      neighbors: impl FnMut(&N) -> I,
+                      heuristic: impl FnMut(&N) -> C,
+                      is_goal: impl FnMut(&N) -> bool) -> Option<Path<N, C>>
+    where N: Eq + Hash + Clone, C: Ord + Copy + Default + Add<Output = C>, I: IntoIterator<Item = (N, C)>
+{
+    let (visited, goal) = astar_visit(starts, neighbors, heuristic, is_goal);
+    Some(reconstruct(&visited, goal?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        /* 0 -> 1 -> 2 -> 3 in steps of 1, plus an expensive shortcut 0 -> 3 */
+        let edges = |&n: &u32| match n {
+            0 => vec![(1, 1), (3, 5)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        };
+        let unweighted = |n: &u32| edges(n).into_iter().map(|(next, _)| next);
+
+        let path = bfs([0], unweighted, |&n| n == 3).unwrap();
+        assert_eq!((path.cost, path.nodes), (1, vec![0, 3]));
+        let path = bfs([2, 0], unweighted, |&n| n == 3).unwrap();
+        assert_eq!((path.goal, path.cost), (3, 1));
+        assert_eq!(bfs([1], unweighted, |&n| n == 0), None);
+        assert_eq!(bfs_distances([0], unweighted), HashMap::from([(0, 0), (1, 1), (2, 2), (3, 1)]));
+
+        let path = dijkstra([0], edges, |&n| n == 3).unwrap();
+        assert_eq!((path.cost, path.nodes), (3, vec![0, 1, 2, 3]));
+        assert_eq!(dijkstra([3], edges, |&n| n == 0), None);
+        assert_eq!(dijkstra_distances([0], edges)[&3], 3);
+
+        /* shortest way through an open field, with an endless one for the unreachable case */
+        let field = |&(x, y): &(i32, i32)| [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                                               .into_iter()
+                                               .filter(|&(x, y)| (0 .. 10).contains(&x) && (0 .. 10).contains(&y))
+                                               .map(|pos| (pos, 1));
+        let path = astar([(0, 0)], field, |&(x, y)| (9 - x) + (9 - y), |&pos| pos == (9, 9)).unwrap();
+        assert_eq!(path.cost, 18);
+        assert_eq!(path.nodes.len(), 19);
+        assert_eq!(astar([(0, 0)], field, |_| 0, |&pos| pos == (10, 10)), None);
+    }
+}
@@ -0,0 +1,113 @@
+use std::any::Any;
+use std::fmt;
+use crate::Error;
+
+/// Result of a puzzle part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// Multi-line output, such as the letters drawn on a display.
+    Art(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(text) | Answer::Art(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Answer {
+                Answer::Number(i64::try_from(n).expect("answer should fit into i64"))
+            }
+        })*
+    }
+}
+
+answer_from_number!(u32, u64, usize, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+/// A puzzle day: the input is parsed once and shared by both parts.
+pub trait Solution {
+    type Input;
+
+    /// Number of parts the puzzle has.
+    const PARTS: usize = 2;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error>;
+    fn part1(&self, input: &Self::Input) -> Result<Answer, Error>;
+    fn part2(&self, input: &Self::Input) -> Result<Answer, Error>;
+}
+
+/// Object-safe form of [`Solution`], so that different days can be driven
+/// through the same interface.
+pub trait DynSolution {
+    fn parts(&self) -> usize;
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, Error>;
+    /// Solves `part` (0 or 1) on input returned by [`DynSolution::parse`].
+    fn solve(&self, input: &dyn Any, part: usize) -> Result<Answer, Error>;
+}
+
+impl<S: Solution> DynSolution for S where S::Input: 'static {
+    fn parts(&self) -> usize {
+        S::PARTS
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, Error> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn solve(&self, input: &dyn Any, part: usize) -> Result<Answer, Error> {
+        let input = input.downcast_ref::<S::Input>().expect("input should come from parse");
+        match part {
+            0 => self.part1(input),
+            1 if part < S::PARTS => self.part2(input),
+            _ => panic!("no part {}", part),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+
+        fn parse(&self, input: &str) -> Result<Vec<u32>, Error> {
+            input.split(',').map(|x| x.parse().map_err(|err| Error::parse(1, x, err))).collect()
+        }
+
+        fn part1(&self, input: &Vec<u32>) -> Result<Answer, Error> {
+            Ok(input.iter().sum::<u32>().into())
+        }
+
+        fn part2(&self, input: &Vec<u32>) -> Result<Answer, Error> {
+            Ok(Answer::Art(input.iter().map(|&n| "#".repeat(n as usize) + "\n").collect()))
+        }
+    }
+
+    #[test]
+    fn test() {
+        let solution : &dyn DynSolution = &Sum;
+        let input = solution.parse("1,2,3").unwrap();
+        assert_eq!(solution.parts(), 2);
+        assert_eq!(solution.solve(input.as_ref(), 0).unwrap(), Answer::Number(6));
+        assert_eq!(solution.solve(input.as_ref(), 1).unwrap().to_string(), "#\n##\n###\n");
+        assert_eq!(solution.parse("1,x").err().unwrap().to_string(), "line 1: invalid digit found in string (\"x\")");
+        assert_eq!(Answer::from(String::from("CMZ")).to_string(), "CMZ");
+    }
+}